@@ -0,0 +1,64 @@
+// Headless emulation speed, in frames per second.
+//
+//   cargo bench --bench frames
+//   TOMBOY_BENCH_ROM=path/to/rom.gb cargo bench --bench frames
+//
+// With --features hashmap_dispatch the opcodes are looked up in a HashMap, as
+// before the dispatch tables, to compare the two.
+
+use std::{env, fs, time::Instant};
+
+use tomboy_emu::Emulator;
+use tomboy_emu::cartrdige::NINTENDO_LOGO;
+
+const FRAMES: usize = 3000;
+const RUNS: usize = 5;
+
+// A busy loop over WRAM, with loads, ALU, CB, stack and branch instructions.
+fn synthetic_rom() -> Vec<u8> {
+  let mut rom = vec![0; 0x8000];
+  rom[0x104 .. 0x134].copy_from_slice(&NINTENDO_LOGO);
+  // JP 0x0150
+  rom[0x100 .. 0x103].copy_from_slice(&[0xc3, 0x50, 0x01]);
+
+  let program = [
+    0x21, 0x00, 0xc0, // LD HL, 0xc000
+    0x2a,             // loop: LD A, (HL+)
+    0x80,             // ADD A, B
+    0x77,             // LD (HL), A
+    0x04,             // INC B
+    0xcb, 0x01,       // RLC C
+    0xcb, 0x5f,       // BIT 3, A
+    0xcd, 0x68, 0x01, // CALL sub
+    0x7c,             // LD A, H
+    0xfe, 0xd0,       // CP 0xd0
+    0x20, 0xf0,       // JR NZ, loop
+    0x21, 0x00, 0xc0, // LD HL, 0xc000
+    0x18, 0xeb,       // JR loop
+    0xc5,             // sub: PUSH BC
+    0xc1,             // POP BC
+    0xc9,             // RET
+  ];
+  rom[0x150 .. 0x150 + program.len()].copy_from_slice(&program);
+  rom
+}
+
+fn main() {
+  let (rom, synthetic) = match env::var("TOMBOY_BENCH_ROM") {
+    Ok(path) => (fs::read(path).expect("Couldn't read the ROM"), false),
+    Err(_) => (synthetic_rom(), true),
+  };
+
+  let mut best = 0.0f64;
+  for run in 1 ..= RUNS {
+    let mut emu = Emulator::new(rom.clone()).unwrap();
+    let start = Instant::now();
+    for _ in 0..FRAMES { emu.step_frame().unwrap(); }
+    let fps = FRAMES as f64 / start.elapsed().as_secs_f64();
+    // make sure the loop is what got measured
+    if synthetic { assert!((0x0150 .. 0x016b).contains(&emu.cpu.pc)); }
+    println!("run {run}: {fps:.0} frames/s");
+    best = best.max(fps);
+  }
+  println!("best: {best:.0} frames/s ({:.1}x real time)", best / 59.7);
+}
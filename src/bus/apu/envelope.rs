@@ -0,0 +1,40 @@
+// https://gbdev.io/pandocs/Audio_Registers.html#ff12--nr12-channel-1-volume--envelope
+pub struct Envelope {
+  pub volume: u8,
+  initial_volume: u8,
+  increase: bool,
+  period: u8,
+  timer: u8,
+}
+
+impl Envelope {
+  pub fn new() -> Self {
+    Envelope { volume: 0, initial_volume: 0, increase: false, period: 0, timer: 0 }
+  }
+
+  pub fn write(&mut self, data: u8) {
+    self.initial_volume = data >> 4;
+    self.increase = data & 0b1000 != 0;
+    self.period = data & 0b111;
+  }
+
+  pub fn trigger(&mut self) {
+    self.volume = self.initial_volume;
+    self.timer = self.reload_value();
+  }
+
+  pub fn clock(&mut self) {
+    if self.period == 0 { return; }
+
+    self.timer = self.timer.saturating_sub(1);
+    if self.timer != 0 { return; }
+
+    self.timer = self.reload_value();
+    if self.increase && self.volume < 15 { self.volume += 1; }
+    if !self.increase && self.volume > 0 { self.volume -= 1; }
+  }
+
+  fn reload_value(&self) -> u8 {
+    if self.period == 0 { 8 } else { self.period }
+  }
+}
@@ -0,0 +1,40 @@
+// https://gbdev.io/pandocs/Audio_details.html#length-timer
+pub struct LengthCounter {
+  pub counter: u16,
+  pub enabled: bool,
+  max: u16,
+}
+
+impl LengthCounter {
+  pub fn new(max: u16) -> Self {
+    LengthCounter { counter: 0, enabled: false, max }
+  }
+
+  pub fn load(&mut self, value: u8) {
+    self.counter = self.max - value as u16;
+  }
+
+  // Returns true when the counter expires and the channel has to be disabled.
+  pub fn clock(&mut self) -> bool {
+    if !self.enabled || self.counter == 0 { return false; }
+
+    self.counter -= 1;
+    self.counter == 0
+  }
+
+  // Enabling the counter when the next frame sequencer step doesn't clock it
+  // gives it an extra clock. Returns true when that expires the counter.
+  pub fn set_enabled(&mut self, enabled: bool, first_half: bool) -> bool {
+    let was_enabled = self.enabled;
+    self.enabled = enabled;
+
+    !was_enabled && first_half && self.clock()
+  }
+
+  pub fn trigger(&mut self, first_half: bool) {
+    if self.counter != 0 { return; }
+
+    self.counter = self.max;
+    if self.enabled && first_half { self.counter -= 1; }
+  }
+}
@@ -0,0 +1,244 @@
+use crate::definitions::CLOCK_SPEED;
+
+mod envelope;
+mod length;
+mod square;
+mod wave;
+mod noise;
+pub mod resampler;
+pub mod wav;
+
+use square::Square;
+use wave::Wave;
+use noise::Noise;
+
+// A stereo sample is produced every M-cycle.
+pub const SAMPLE_RATE: usize = CLOCK_SPEED / 4;
+// Samples are dropped when nobody consumes them, to not grow the buffer forever.
+const MAX_BUFFERED_SAMPLES: usize = SAMPLE_RATE;
+
+// Bits that always read as 1 in 0xff10..=0xff2f.
+// https://gbdev.io/pandocs/Audio_Registers.html
+const READ_MASKS: [u8; 32] = [
+  0x80, 0x3f, 0x00, 0xff, 0xbf,
+  0xff, 0x3f, 0x00, 0xff, 0xbf,
+  0x7f, 0xff, 0x9f, 0xff, 0xbf,
+  0xff, 0xff, 0x00, 0x00, 0xbf,
+  0x00, 0x00, 0x70,
+  0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+];
+
+pub struct APU {
+  pub ch1: Square,
+  pub ch2: Square,
+  pub ch3: Wave,
+  pub ch4: Noise,
+
+  powered: bool,
+  nr50: u8,
+  nr51: u8,
+  // bit n set silences channel n + 1 in the mixed output, without changing the emulation
+  pub mute_mask: u8,
+
+  // the next step of the 512 Hz frame sequencer, clocked by DIV
+  frame_step: u8,
+  cycles: usize,
+
+  // DC offset removal, as the capacitors on the real hardware output
+  capacitors: (f32, f32),
+  channel_capacitors: [(f32, f32); 4],
+  pub samples: Vec<(f32, f32)>,
+  // the output of every channel on its own, only collected when Some
+  pub channel_samples: Option<Vec<[(f32, f32); 4]>>,
+}
+
+impl Default for APU {
+  fn default() -> Self { Self::new() }
+}
+
+impl APU {
+  pub fn new() -> Self {
+    APU {
+      ch1: Square::new(true),
+      ch2: Square::new(false),
+      ch3: Wave::new(),
+      ch4: Noise::new(),
+      // left on by the boot rom
+      powered: true,
+      nr50: 0, nr51: 0,
+      mute_mask: 0,
+      frame_step: 0,
+      cycles: 0,
+      capacitors: (0., 0.),
+      channel_capacitors: [(0., 0.); 4],
+      samples: Vec::new(),
+      channel_samples: None,
+    }
+  }
+
+  pub fn read(&self, addr: u16) -> u8 {
+    let value = match addr {
+      0xff10 ..= 0xff14 => self.ch1.regs[(addr - 0xff10) as usize],
+      0xff15 ..= 0xff19 => self.ch2.regs[(addr - 0xff15) as usize],
+      0xff1a ..= 0xff1e => self.ch3.regs[(addr - 0xff1a) as usize],
+      0xff1f ..= 0xff23 => self.ch4.regs[(addr - 0xff1f) as usize],
+      0xff24 => self.nr50,
+      0xff25 => self.nr51,
+      0xff26 => {
+        (self.powered as u8) << 7
+          | (self.ch4.enabled as u8) << 3
+          | (self.ch3.enabled as u8) << 2
+          | (self.ch2.enabled as u8) << 1
+          | self.ch1.enabled as u8
+      }
+      0xff30 ..= 0xff3f => return self.ch3.ram[(addr - 0xff30) as usize],
+      _ => 0,
+    };
+
+    value | READ_MASKS[(addr - 0xff10) as usize]
+  }
+
+  pub fn write(&mut self, addr: u16, data: u8) {
+    if addr == 0xff26 {
+      self.set_power(data & 0x80 != 0);
+      return;
+    }
+
+    if (0xff30 ..= 0xff3f).contains(&addr) {
+      self.ch3.ram[(addr - 0xff30) as usize] = data;
+      return;
+    }
+
+    // while powered off only the length timers can be written
+    if !self.powered {
+      match addr {
+        0xff11 => self.ch1.write_length(data),
+        0xff16 => self.ch2.write_length(data),
+        0xff1b => self.ch3.write_length(data),
+        0xff20 => self.ch4.write_length(data),
+        _ => {}
+      }
+      return;
+    }
+
+    let first_half = self.frame_step % 2 == 1;
+    match addr {
+      0xff10 ..= 0xff14 => self.ch1.write((addr - 0xff10) as usize, data, first_half),
+      0xff15 ..= 0xff19 => self.ch2.write((addr - 0xff15) as usize, data, first_half),
+      0xff1a ..= 0xff1e => self.ch3.write((addr - 0xff1a) as usize, data, first_half),
+      0xff1f ..= 0xff23 => self.ch4.write((addr - 0xff1f) as usize, data, first_half),
+      0xff24 => self.nr50 = data,
+      0xff25 => self.nr51 = data,
+      _ => {}
+    }
+  }
+
+  // Turning the APU off clears all the registers, except the wave RAM.
+  fn set_power(&mut self, on: bool) {
+    if on && !self.powered { self.frame_step = 0; }
+
+    if !on && self.powered {
+      let wave_ram = self.ch3.ram;
+      self.ch1 = Square::new(true);
+      self.ch2 = Square::new(false);
+      self.ch3 = Wave::new();
+      self.ch3.ram = wave_ram;
+      self.ch4 = Noise::new();
+      self.nr50 = 0;
+      self.nr51 = 0;
+    }
+
+    self.powered = on;
+  }
+
+  // https://gbdev.io/pandocs/Audio_details.html#div-apu
+  pub fn clock_frame_sequencer(&mut self) {
+    if !self.powered { return; }
+
+    if self.frame_step.is_multiple_of(2) {
+      self.ch1.clock_length();
+      self.ch2.clock_length();
+      self.ch3.clock_length();
+      self.ch4.clock_length();
+    }
+    if self.frame_step == 2 || self.frame_step == 6 {
+      self.ch1.clock_sweep();
+    }
+    if self.frame_step == 7 {
+      self.ch1.clock_envelope();
+      self.ch2.clock_envelope();
+      self.ch4.clock_envelope();
+    }
+
+    self.frame_step = (self.frame_step + 1) % 8;
+  }
+
+  pub fn step(&mut self, cycles: usize) {
+    for _ in 0..cycles {
+      if self.powered {
+        self.ch1.step();
+        self.ch2.step();
+        self.ch3.step();
+        self.ch4.step();
+      }
+
+      self.cycles += 1;
+      if self.cycles == 4 {
+        self.cycles = 0;
+        self.push_sample();
+      }
+    }
+  }
+
+  fn push_sample(&mut self) {
+    if self.samples.len() >= MAX_BUFFERED_SAMPLES { return; }
+
+    let channels = self.channel_outputs();
+    let (mut left, mut right) = (0., 0.);
+    for (i, &(channel_left, channel_right)) in channels.iter().enumerate() {
+      if self.mute_mask & (1 << i) != 0 { continue; }
+      left += channel_left;
+      right += channel_right;
+    }
+    self.samples.push(high_pass(&mut self.capacitors, (left, right)));
+
+    if let Some(channel_samples) = &mut self.channel_samples {
+      let filtered = std::array::from_fn(|i| high_pass(&mut self.channel_capacitors[i], channels[i]));
+      channel_samples.push(filtered);
+    }
+  }
+
+  // Every DAC maps the 0..=15 channel output to -1.0..=1.0, and NR51 selects
+  // where each channel goes. NR50 scales the volume of each side.
+  fn channel_outputs(&self) -> [(f32, f32); 4] {
+    let channels = [
+      (self.ch1.output(), self.ch1.dac_enabled()),
+      (self.ch2.output(), self.ch2.dac_enabled()),
+      (self.ch3.output(), self.ch3.dac_enabled()),
+      (self.ch4.output(), self.ch4.dac_enabled()),
+    ];
+
+    let left_volume = ((self.nr50 >> 4) & 0b111) as f32 + 1.;
+    let right_volume = (self.nr50 & 0b111) as f32 + 1.;
+
+    let mut outputs = [(0., 0.); 4];
+    for (i, (output, dac_enabled)) in channels.into_iter().enumerate() {
+      if !dac_enabled { continue; }
+
+      let analog = (output as f32 / 7.5 - 1.) / 4.;
+      if self.nr51 & (0x10 << i) != 0 { outputs[i].0 = analog * left_volume / 8.; }
+      if self.nr51 & (0x01 << i) != 0 { outputs[i].1 = analog * right_volume / 8.; }
+    }
+    outputs
+  }
+}
+
+// https://gbdev.io/pandocs/Audio_details.html#obscure-behavior
+fn high_pass(capacitor: &mut (f32, f32), input: (f32, f32)) -> (f32, f32) {
+  // 0.999958 per T-cycle, to the power of 4 per sample
+  const CHARGE_FACTOR: f32 = 0.999832;
+
+  let output = (input.0 - capacitor.0, input.1 - capacitor.1);
+  *capacitor = (input.0 - output.0 * CHARGE_FACTOR, input.1 - output.1 * CHARGE_FACTOR);
+  output
+}
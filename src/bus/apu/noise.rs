@@ -0,0 +1,94 @@
+use super::{envelope::Envelope, length::LengthCounter};
+
+const DIVISORS: [usize; 8] = [8, 16, 32, 48, 64, 80, 96, 112];
+
+// https://gbdev.io/pandocs/Audio_Registers.html#sound-channel-4--noise
+pub struct Noise {
+  pub enabled: bool,
+  pub regs: [u8; 5],
+
+  length: LengthCounter,
+  envelope: Envelope,
+  timer: usize,
+  lfsr: u16,
+}
+
+impl Noise {
+  pub fn new() -> Self {
+    Noise {
+      enabled: false, regs: [0; 5],
+      length: LengthCounter::new(64),
+      envelope: Envelope::new(),
+      timer: 0, lfsr: 0,
+    }
+  }
+
+  // regs[0] is the unused 0xff1f, so registers are numbered as the other channels
+  pub fn write(&mut self, reg: usize, data: u8, first_half: bool) {
+    self.regs[reg] = data;
+
+    match reg {
+      1 => self.length.load(data & 0x3f),
+      2 => {
+        self.envelope.write(data);
+        if !self.dac_enabled() { self.enabled = false; }
+      }
+      4 => {
+        let trigger = data & 0x80 != 0;
+        if self.length.set_enabled(data & 0x40 != 0, first_half) && !trigger {
+          self.enabled = false;
+        }
+        if trigger { self.trigger(first_half); }
+      }
+      _ => {}
+    }
+  }
+
+  pub fn write_length(&mut self, data: u8) {
+    self.length.load(data & 0x3f);
+  }
+
+  fn trigger(&mut self, first_half: bool) {
+    self.enabled = self.dac_enabled();
+    self.length.trigger(first_half);
+    self.envelope.trigger();
+    self.timer = self.period();
+    self.lfsr = 0x7fff;
+  }
+
+  pub fn step(&mut self) {
+    self.timer = self.timer.saturating_sub(1);
+    if self.timer != 0 { return; }
+    self.timer = self.period();
+
+    // shifts 14 and 15 don't clock the lfsr
+    if self.regs[3] >> 4 >= 14 { return; }
+
+    let xor = (self.lfsr & 1) ^ ((self.lfsr >> 1) & 1);
+    self.lfsr = (self.lfsr >> 1) | (xor << 14);
+    if self.regs[3] & 0b1000 != 0 {
+      self.lfsr = (self.lfsr & !(1 << 6)) | (xor << 6);
+    }
+  }
+
+  pub fn clock_length(&mut self) {
+    if self.length.clock() { self.enabled = false; }
+  }
+
+  pub fn clock_envelope(&mut self) {
+    self.envelope.clock();
+  }
+
+  pub fn output(&self) -> u8 {
+    if !self.enabled || self.lfsr & 1 != 0 { return 0; }
+    self.envelope.volume
+  }
+
+  pub fn dac_enabled(&self) -> bool {
+    self.regs[2] & 0xf8 != 0
+  }
+
+  fn period(&self) -> usize {
+    DIVISORS[self.regs[3] as usize & 0b111] << (self.regs[3] >> 4)
+  }
+}
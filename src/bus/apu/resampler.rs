@@ -0,0 +1,89 @@
+use std::f64::consts::PI;
+
+// Second order low pass section, from the Audio EQ Cookbook.
+// https://www.w3.org/TR/audio-eq-cookbook/
+#[derive(Clone, Copy)]
+struct Biquad {
+  b0: f64, b1: f64, b2: f64,
+  a1: f64, a2: f64,
+  x1: f64, x2: f64,
+  y1: f64, y2: f64,
+}
+
+impl Biquad {
+  fn low_pass(sample_rate: f64, cutoff: f64, q: f64) -> Self {
+    let w0 = 2. * PI * cutoff / sample_rate;
+    let alpha = w0.sin() / (2. * q);
+    let cos = w0.cos();
+    let a0 = 1. + alpha;
+
+    Biquad {
+      b0: (1. - cos) / 2. / a0,
+      b1: (1. - cos) / a0,
+      b2: (1. - cos) / 2. / a0,
+      a1: -2. * cos / a0,
+      a2: (1. - alpha) / a0,
+      x1: 0., x2: 0., y1: 0., y2: 0.,
+    }
+  }
+
+  fn process(&mut self, x: f64) -> f64 {
+    let y = self.b0 * x + self.b1 * self.x1 + self.b2 * self.x2 - self.a1 * self.y1 - self.a2 * self.y2;
+    (self.x2, self.x1) = (self.x1, x);
+    (self.y2, self.y1) = (self.y1, y);
+    y
+  }
+}
+
+// The Q of the two sections of a 4th order Butterworth filter
+const BUTTERWORTH_Q: [f64; 2] = [0.541196, 1.306563];
+
+// Converts the APU output to a rate a sound card can play. The signal is band
+// limited by a 4th order low pass filter below the new Nyquist frequency, then
+// linearly interpolated at the output sample times.
+pub struct Resampler {
+  // input samples between two output ones
+  step: f64,
+  // time of the next output sample, from the previous input sample
+  position: f64,
+  filters: [[Biquad; 2]; 2],
+  previous: (f64, f64),
+}
+
+impl Resampler {
+  pub fn new(input_rate: usize, output_rate: usize) -> Self {
+    let cutoff = (output_rate as f64 * 0.45).min(20_000.);
+    let section = |q| Biquad::low_pass(input_rate as f64, cutoff, q);
+    let channel = [section(BUTTERWORTH_Q[0]), section(BUTTERWORTH_Q[1])];
+
+    Resampler {
+      step: input_rate as f64 / output_rate as f64,
+      position: 0.,
+      filters: [channel, channel],
+      previous: (0., 0.),
+    }
+  }
+
+  pub fn process(&mut self, input: &[(f32, f32)], output: &mut Vec<(f32, f32)>) {
+    for &(left, right) in input {
+      let left = self.filter(0, left as f64);
+      let right = self.filter(1, right as f64);
+
+      while self.position < 1. {
+        let t = self.position;
+        output.push((
+          (self.previous.0 + (left - self.previous.0) * t) as f32,
+          (self.previous.1 + (right - self.previous.1) * t) as f32,
+        ));
+        self.position += self.step;
+      }
+
+      self.position -= 1.;
+      self.previous = (left, right);
+    }
+  }
+
+  fn filter(&mut self, channel: usize, sample: f64) -> f64 {
+    self.filters[channel].iter_mut().fold(sample, |sample, section| section.process(sample))
+  }
+}
@@ -0,0 +1,154 @@
+use super::{envelope::Envelope, length::LengthCounter};
+
+const DUTY_TABLE: [[u8; 8]; 4] = [
+  [0, 0, 0, 0, 0, 0, 0, 1],
+  [1, 0, 0, 0, 0, 0, 0, 1],
+  [1, 0, 0, 0, 0, 1, 1, 1],
+  [0, 1, 1, 1, 1, 1, 1, 0],
+];
+
+// Channels 1 and 2, only the first one has the frequency sweep.
+// https://gbdev.io/pandocs/Audio_Registers.html#sound-channel-1--pulse-with-period-sweep
+pub struct Square {
+  pub enabled: bool,
+  pub regs: [u8; 5],
+  has_sweep: bool,
+
+  length: LengthCounter,
+  envelope: Envelope,
+  timer: usize,
+  duty_step: usize,
+
+  sweep_enabled: bool,
+  sweep_timer: u8,
+  shadow_frequency: u16,
+  // clearing the negate bit after a subtraction was computed disables the channel
+  sweep_negated: bool,
+}
+
+impl Square {
+  pub fn new(has_sweep: bool) -> Self {
+    Square {
+      enabled: false, regs: [0; 5], has_sweep,
+      length: LengthCounter::new(64),
+      envelope: Envelope::new(),
+      timer: 0, duty_step: 0,
+      sweep_enabled: false, sweep_timer: 0,
+      shadow_frequency: 0, sweep_negated: false,
+    }
+  }
+
+  pub fn write(&mut self, reg: usize, data: u8, first_half: bool) {
+    self.regs[reg] = data;
+
+    match reg {
+      0 => if self.sweep_negated && data & 0b1000 == 0 { self.enabled = false; },
+      1 => self.length.load(data & 0x3f),
+      2 => {
+        self.envelope.write(data);
+        if !self.dac_enabled() { self.enabled = false; }
+      }
+      3 => {}
+      _ => {
+        let trigger = data & 0x80 != 0;
+        if self.length.set_enabled(data & 0x40 != 0, first_half) && !trigger {
+          self.enabled = false;
+        }
+        if trigger { self.trigger(first_half); }
+      }
+    }
+  }
+
+  pub fn write_length(&mut self, data: u8) {
+    self.length.load(data & 0x3f);
+  }
+
+  fn trigger(&mut self, first_half: bool) {
+    self.enabled = self.dac_enabled();
+    self.length.trigger(first_half);
+    self.envelope.trigger();
+    self.timer = self.period();
+
+    if self.has_sweep {
+      let (period, shift) = (self.sweep_period(), self.regs[0] & 0b111);
+      self.shadow_frequency = self.frequency();
+      self.sweep_timer = if period == 0 { 8 } else { period };
+      self.sweep_enabled = period != 0 || shift != 0;
+      self.sweep_negated = false;
+
+      if shift != 0 { self.sweep_frequency(); }
+    }
+  }
+
+  pub fn step(&mut self) {
+    self.timer = self.timer.saturating_sub(1);
+    if self.timer == 0 {
+      self.timer = self.period();
+      self.duty_step = (self.duty_step + 1) % 8;
+    }
+  }
+
+  pub fn clock_length(&mut self) {
+    if self.length.clock() { self.enabled = false; }
+  }
+
+  pub fn clock_envelope(&mut self) {
+    self.envelope.clock();
+  }
+
+  pub fn clock_sweep(&mut self) {
+    self.sweep_timer = self.sweep_timer.saturating_sub(1);
+    if self.sweep_timer != 0 { return; }
+
+    let period = self.sweep_period();
+    self.sweep_timer = if period == 0 { 8 } else { period };
+    if !self.sweep_enabled || period == 0 { return; }
+
+    let frequency = self.sweep_frequency();
+    if frequency <= 2047 && self.regs[0] & 0b111 != 0 {
+      self.shadow_frequency = frequency;
+      self.regs[3] = frequency as u8;
+      self.regs[4] = (self.regs[4] & !0b111) | (frequency >> 8) as u8;
+
+      // the new frequency is checked for overflow again, but not written back
+      self.sweep_frequency();
+    }
+  }
+
+  // Computes the next frequency, disabling the channel if it overflows.
+  fn sweep_frequency(&mut self) -> u16 {
+    let delta = self.shadow_frequency >> (self.regs[0] & 0b111);
+    let frequency = if self.regs[0] & 0b1000 != 0 {
+      self.sweep_negated = true;
+      self.shadow_frequency - delta
+    } else {
+      self.shadow_frequency + delta
+    };
+
+    if frequency > 2047 { self.enabled = false; }
+    frequency
+  }
+
+  pub fn output(&self) -> u8 {
+    if !self.enabled { return 0; }
+
+    let duty = (self.regs[1] >> 6) as usize;
+    DUTY_TABLE[duty][self.duty_step] * self.envelope.volume
+  }
+
+  pub fn dac_enabled(&self) -> bool {
+    self.regs[2] & 0xf8 != 0
+  }
+
+  fn sweep_period(&self) -> u8 {
+    (self.regs[0] >> 4) & 0b111
+  }
+
+  fn frequency(&self) -> u16 {
+    (self.regs[4] as u16 & 0b111) << 8 | self.regs[3] as u16
+  }
+
+  fn period(&self) -> usize {
+    (2048 - self.frequency() as usize) * 4
+  }
+}
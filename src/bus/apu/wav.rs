@@ -0,0 +1,38 @@
+use std::io::{self, Write};
+
+// Writes 16 bit stereo PCM samples in a RIFF WAVE container.
+// http://soundfile.sapp.org/doc/WaveFormat/
+pub fn write_wav(mut writer: impl Write, sample_rate: u32, samples: &[(f32, f32)]) -> io::Result<()> {
+  const CHANNELS: u16 = 2;
+  const BITS_PER_SAMPLE: u16 = 16;
+  let block_align = CHANNELS * BITS_PER_SAMPLE / 8;
+  let data_size = samples.len() as u32 * block_align as u32;
+
+  writer.write_all(b"RIFF")?;
+  writer.write_all(&(36 + data_size).to_le_bytes())?;
+  writer.write_all(b"WAVE")?;
+
+  writer.write_all(b"fmt ")?;
+  writer.write_all(&16u32.to_le_bytes())?;
+  // PCM
+  writer.write_all(&1u16.to_le_bytes())?;
+  writer.write_all(&CHANNELS.to_le_bytes())?;
+  writer.write_all(&sample_rate.to_le_bytes())?;
+  writer.write_all(&(sample_rate * block_align as u32).to_le_bytes())?;
+  writer.write_all(&block_align.to_le_bytes())?;
+  writer.write_all(&BITS_PER_SAMPLE.to_le_bytes())?;
+
+  writer.write_all(b"data")?;
+  writer.write_all(&data_size.to_le_bytes())?;
+
+  let mut data = Vec::with_capacity(data_size as usize);
+  for &(left, right) in samples {
+    data.extend(to_pcm(left).to_le_bytes());
+    data.extend(to_pcm(right).to_le_bytes());
+  }
+  writer.write_all(&data)
+}
+
+fn to_pcm(sample: f32) -> i16 {
+  (sample.clamp(-1., 1.) * i16::MAX as f32) as i16
+}
@@ -0,0 +1,87 @@
+use super::length::LengthCounter;
+
+// https://gbdev.io/pandocs/Audio_Registers.html#sound-channel-3--wave-output
+pub struct Wave {
+  pub enabled: bool,
+  pub regs: [u8; 5],
+  pub ram: [u8; 16],
+
+  length: LengthCounter,
+  timer: usize,
+  position: usize,
+  sample: u8,
+}
+
+impl Wave {
+  pub fn new() -> Self {
+    Wave {
+      enabled: false, regs: [0; 5], ram: [0; 16],
+      length: LengthCounter::new(256),
+      timer: 0, position: 0, sample: 0,
+    }
+  }
+
+  pub fn write(&mut self, reg: usize, data: u8, first_half: bool) {
+    self.regs[reg] = data;
+
+    match reg {
+      0 => if !self.dac_enabled() { self.enabled = false; },
+      1 => self.length.load(data),
+      2 | 3 => {}
+      _ => {
+        let trigger = data & 0x80 != 0;
+        if self.length.set_enabled(data & 0x40 != 0, first_half) && !trigger {
+          self.enabled = false;
+        }
+        if trigger { self.trigger(first_half); }
+      }
+    }
+  }
+
+  pub fn write_length(&mut self, data: u8) {
+    self.length.load(data);
+  }
+
+  fn trigger(&mut self, first_half: bool) {
+    self.enabled = self.dac_enabled();
+    self.length.trigger(first_half);
+    self.timer = self.period();
+    self.position = 0;
+  }
+
+  pub fn step(&mut self) {
+    self.timer = self.timer.saturating_sub(1);
+    if self.timer == 0 {
+      self.timer = self.period();
+      self.position = (self.position + 1) % 32;
+
+      // two samples per byte, the high nibble is played first
+      let byte = self.ram[self.position / 2];
+      self.sample = if self.position.is_multiple_of(2) { byte >> 4 } else { byte & 0x0f };
+    }
+  }
+
+  pub fn clock_length(&mut self) {
+    if self.length.clock() { self.enabled = false; }
+  }
+
+  pub fn output(&self) -> u8 {
+    if !self.enabled { return 0; }
+
+    match (self.regs[2] >> 5) & 0b11 {
+      0 => 0,
+      1 => self.sample,
+      2 => self.sample >> 1,
+      _ => self.sample >> 2,
+    }
+  }
+
+  pub fn dac_enabled(&self) -> bool {
+    self.regs[0] & 0x80 != 0
+  }
+
+  fn period(&self) -> usize {
+    let frequency = (self.regs[4] as usize & 0b111) << 8 | self.regs[3] as usize;
+    (2048 - frequency) * 2
+  }
+}
@@ -1,32 +1,73 @@
 const DMA_TRANSFER_SIZE: usize = 160;
+// T-cycles between the write to 0xff46 and the start of the transfer
 const DMA_START_DELAY: usize = 4;
+// a byte is copied every M-cycle
+const CYCLES_PER_BYTE: usize = 4;
 
+// https://gbdev.io/pandocs/OAM_DMA_Transfer.html
 pub struct DMA {
+  // the last value written to 0xff46
+  pub register: u8,
+  // true while copying, the CPU can't use the bus the DMA reads from, nor OAM
   pub active: bool,
   pub source: u16,
   pub bytes: usize,
+  // the byte on the bus, read by the CPU on a conflict
+  pub last_byte: u8,
+
+  // a transfer starting after a delay, the previous one goes on meanwhile
+  pending: Option<(u16, usize)>,
+  cycles: usize,
 }
 
 impl DMA {
   pub fn new() -> DMA {
-    DMA { active: false, source: 0, bytes: 0 }
+    DMA {
+      register: 0, active: false, source: 0, bytes: 0, last_byte: 0xff,
+      pending: None, cycles: 0,
+    }
   }
 
   pub fn write(&mut self, src: u8) {
-    self.source = u16::from_be_bytes([src, 0x00]);
-    self.bytes = 0;
-    self.active = true;
+    self.register = src;
+    // 0xe000..=0xffff reads the echo of WRAM
+    let src = if src >= 0xe0 { src - 0x20 } else { src };
+    self.pending = Some((u16::from_be_bytes([src, 0x00]), DMA_START_DELAY));
   }
 
-  pub fn step(&mut self, mut cycles: usize) -> usize {
-    if self.bytes < DMA_START_DELAY {
-      cycles = if cycles < DMA_START_DELAY { 0 }
-      else { cycles - DMA_START_DELAY }
+  // Advances a T-cycle, returns the source address and OAM offset of the byte to copy.
+  pub fn step(&mut self) -> Option<(u16, usize)> {
+    let transfer = self.copy_step();
+
+    if let Some((source, delay)) = &mut self.pending {
+      *delay -= 1;
+      if *delay == 0 {
+        self.source = *source;
+        self.bytes = 0;
+        self.cycles = 0;
+        self.active = true;
+        self.pending = None;
+      }
     }
-    
-    if self.bytes + cycles >= DMA_TRANSFER_SIZE + DMA_START_DELAY {
-      self.active = false;
-      self.bytes + cycles - (DMA_TRANSFER_SIZE + DMA_START_DELAY) 
-    } else { cycles }
+    transfer
+  }
+
+  fn copy_step(&mut self) -> Option<(u16, usize)> {
+    if !self.active { return None; }
+
+    self.cycles += 1;
+    if self.cycles < CYCLES_PER_BYTE { return None; }
+    self.cycles = 0;
+
+    let transfer = (self.source + self.bytes as u16, self.bytes);
+    self.bytes += 1;
+    if self.bytes == DMA_TRANSFER_SIZE { self.active = false; }
+    Some(transfer)
   }
-}
\ No newline at end of file
+
+  // VRAM has its own bus, everything else outside of OAM, IO and HRAM shares the external one.
+  pub fn conflicts_with(&self, addr: u16) -> bool {
+    let vram = |addr| (0x8000 ..= 0x9fff).contains(&addr);
+    self.active && addr < 0xfe00 && vram(addr) == vram(self.source)
+  }
+}
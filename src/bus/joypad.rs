@@ -0,0 +1,61 @@
+use bitflags::bitflags;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Button {
+  Right, Left, Up, Down,
+  A, B, Select, Start,
+}
+
+bitflags! {
+  // the select lines are active low too
+  #[derive(Clone, Copy)]
+  pub struct JoypadSelect: u8 {
+    const DIRECTIONS = 1 << 4;
+    const ACTIONS    = 1 << 5;
+  }
+}
+
+// https://gbdev.io/pandocs/Joypad_Input.html
+pub struct Joypad {
+  select: JoypadSelect,
+  // one bit per button, in the order of `Button`: low nibble for directions, high for actions
+  pressed: u8,
+}
+
+impl Joypad {
+  pub fn new() -> Self {
+    Joypad { select: JoypadSelect::all(), pressed: 0 }
+  }
+
+  pub fn read(&self) -> u8 {
+    0xc0 | self.select.bits() | self.lines()
+  }
+
+  // Returns true when a line went from high to low, requesting the joypad interrupt.
+  pub fn write(&mut self, data: u8) -> bool {
+    self.update(|joypad| joypad.select = JoypadSelect::from_bits_truncate(data))
+  }
+
+  pub fn set_button(&mut self, button: Button, pressed: bool) -> bool {
+    let bit = 1 << button as u8;
+    self.update(|joypad| if pressed { joypad.pressed |= bit } else { joypad.pressed &= !bit })
+  }
+
+  fn update(&mut self, f: impl FnOnce(&mut Self)) -> bool {
+    let before = self.lines();
+    f(self);
+    before & !self.lines() != 0
+  }
+
+  // The 4 input lines, 0 when a button of a selected group is pressed.
+  fn lines(&self) -> u8 {
+    let mut pressed = 0;
+    if !self.select.contains(JoypadSelect::DIRECTIONS) { pressed |= self.pressed & 0x0f; }
+    if !self.select.contains(JoypadSelect::ACTIONS) { pressed |= self.pressed >> 4; }
+    !pressed & 0x0f
+  }
+}
+
+impl Default for Joypad {
+  fn default() -> Self { Self::new() }
+}
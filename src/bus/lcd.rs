@@ -1,3 +1,5 @@
+use crate::definitions::{LCDC_INIT, STAT_INIT, BGP_INIT};
+
 bitflags::bitflags! {
   #[derive(Clone, Copy)]
   pub struct LCDControl: u8 {
@@ -47,11 +49,12 @@ pub struct LCD {
 }
 
 impl LCD {
+  // the state left by the boot rom
   pub fn new() -> LCD {
     LCD {
-      ly: 0, lyc: 0, ctrl: LCDControl::new(0), stat: LCDStatus::new(0),
+      ly: 0, lyc: 0, ctrl: LCDControl::new(LCDC_INIT), stat: LCDStatus::new(STAT_INIT),
       scroll: (0,0), window: (0,0),
-      bg_palette: 0, obj_palette0: 0, obj_palette1: 0,
+      bg_palette: BGP_INIT, obj_palette0: 0, obj_palette1: 0,
     }
   }
 }
\ No newline at end of file
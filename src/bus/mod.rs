@@ -1,14 +1,20 @@
-use crate::definitions::*;
+use crate::{definitions::*, cartrdige::Cartridge, ppu::PPU};
 use bitflags::bitflags;
 use log::{info, warn};
 
 mod timer;
 pub mod lcd;
 mod dma;
+pub mod apu;
+pub mod joypad;
+pub mod serial;
 
 use timer::Timer;
-use lcd::{LCD, LCDControl, LCDStatus};
+use lcd::{LCDControl, LCDStatus};
 use dma::DMA;
+use apu::APU;
+use joypad::{Joypad, Button};
+use serial::Serial;
 
 bitflags! {
   #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -20,6 +26,7 @@ bitflags! {
     const JOYPAD = 1 << 4;
   }
 
+  #[derive(Debug, Clone, Copy, PartialEq, Eq)]
   pub struct SerialControl: u8 {
     const CLOCK_SELECT = 1 << 0;
     const CLOCK_SPEED  = 1 << 1;
@@ -35,103 +42,139 @@ impl SerialControl {
 }
 
 pub struct BUS {
-  pub rom: Vec<u8>,
-  pub vram: [u8; 1024 * 8],
+  pub cartridge: Box<dyn Cartridge>,
   pub wram: [u8; 1024 * 8],
-  pub oam: [u8; 160],
   pub hram: [u8; 128],
 
   pub timer: Timer,
-  pub lcd: LCD,
+  pub ppu: PPU,
+  pub apu: APU,
+  pub joypad: Joypad,
+  pub serial: Serial,
   pub dma: DMA,
   ie_reg: InterruptRegister,
   if_reg: InterruptRegister,
 
-  vram_lock: bool,
-  oam_lock: bool,
+  // follow the PPU mode: VRAM is not accessible in mode 3, OAM in modes 2 and 3
+  pub vram_lock: bool,
+  pub oam_lock: bool,
 
   io_regs: [u8; 128],
-  serial_transfer: [u8; 2],
+
+  // T-cycles elapsed since power on
+  pub cycles: usize,
 }
 
 impl BUS {
-  pub fn new(mut rom: Vec<u8>) -> Self {
-    rom.resize(ROM_END as usize + 1,0);
-
+  pub fn new(cartridge: Box<dyn Cartridge>) -> Self {
     BUS {
-      rom,
-      vram: [0; 1024 * 8],
+      cartridge,
       wram: [0; 1024 * 8],
-      oam: [0; 160],
       hram: [0; 128],
 
       vram_lock: false,
       oam_lock: false,
 
       timer: Timer::new(),
-      lcd: LCD::new(),
+      ppu: PPU::new(),
+      apu: APU::new(),
+      joypad: Joypad::new(),
+      serial: Serial::new(),
       dma: DMA::new(),
       ie_reg: InterruptRegister::new(0),
       if_reg: InterruptRegister::new(0),
 
       io_regs: [0; 128],
-      serial_transfer: [0; 2],
+      cycles: 0,
     }
   }
 
   pub fn tick(&mut self, cycles: usize) {
-    let tima_overflow = self.timer.step(cycles);
-    if tima_overflow {
+    // the frame sequencer is clocked by the falling edge of DIV bit 4
+    let div = self.timer.div as usize;
+    let div_edges = ((div + cycles) >> 13) - (div >> 13);
+
+    let tima_reload = self.timer.step(cycles);
+    if tima_reload {
       self.if_reg.insert(InterruptRegister::TIMER);
-      info!("[BUS] TIMA overflowed. Interrupt requested {:?}", self.if_reg);
+      info!("[BUS] TIMA reloaded. Interrupt requested {:?}", self.if_reg);
     }
 
-    if self.dma.active {
-      let to_transfer = self.dma.step(cycles);
-      for i in 0..to_transfer {
-        let src = self.dma.source + (self.dma.bytes + i ) as u16;
-        let dst = u16::from_be_bytes([0xFE, (self.dma.bytes + i) as u8]);
-
-        let byte = self.mem_read(src);
-        self.mem_write(dst, byte);
+    for _ in 0..cycles {
+      if let Some((src, dst)) = self.dma.step() {
+        // the DMA writes to OAM even when the PPU locks it
+        self.dma.last_byte = self.dma_read(src);
+        self.ppu.oam[dst] = self.dma.last_byte;
       }
+    }
+
+    self.cartridge.tick(cycles);
+
+    if self.serial.step(cycles) {
+      self.if_reg.insert(InterruptRegister::SERIAL);
+    }
+
+    for _ in 0..div_edges { self.apu.clock_frame_sequencer(); }
+    self.apu.step(cycles);
+
+    for _ in 0..cycles { self.ppu.step(); }
+    self.if_reg.insert(self.ppu.interrupts);
+    self.ppu.interrupts = InterruptRegister::empty();
+    self.vram_lock = self.ppu.vram_locked();
+    self.oam_lock = self.ppu.oam_locked();
+
+    self.cycles += cycles;
+  }
+
+  // During STOP the whole system clock is halted, time only passes for the frontend.
+  pub fn idle(&mut self, cycles: usize) {
+    self.cycles += cycles;
+  }
 
-      self.dma.bytes += to_transfer;
+  // STOP switches the CGB between single and double speed when KEY1 asks for
+  // it, the DMG has no such register and always goes to sleep.
+  pub fn switch_speed(&mut self) -> bool {
+    false
+  }
+
+  pub fn set_button(&mut self, button: Button, pressed: bool) {
+    if self.joypad.set_button(button, pressed) {
+      self.if_reg.insert(InterruptRegister::JOYPAD);
     }
+  }
+
+  // Called by the other end of the link cable when it clocks a byte to this side.
+  pub fn serial_receive(&mut self, data: u8) -> u8 {
+    let (sent, completed) = self.serial.receive(data);
+    if completed { self.if_reg.insert(InterruptRegister::SERIAL); }
+    sent
+  }
 
-    self.lcd.ly = self.lcd.ly.wrapping_add(1);
+  // The DMA reads straight from the memory, sources are always below 0xe000.
+  fn dma_read(&self, addr: u16) -> u8 {
+    match addr {
+      0x0000 ..= 0x7fff | 0xa000 ..= 0xbfff => self.cartridge.read(addr),
+      0x8000 ..= 0x9fff => self.ppu.vram[(addr - 0x8000) as usize],
+      _ => self.wram[((addr - 0xc000) & 0x1fff) as usize],
+    }
   }
 
   pub fn mem_read(&self, addr: u16) -> u8 {
+    // during a DMA the CPU reads the byte being copied on the same bus, and nothing from OAM
+    if self.dma.conflicts_with(addr) { return self.dma.last_byte; }
+
     match addr {
-      0x0000 ..= 0x7fff => self.rom[addr as usize],
-      0x8000 ..= 0x9fff => self.vram[(addr - 0x8000) as usize],
-      0xa000 ..= 0xbfff => { eprintln!("EXT RAM address range not implemented."); 0 },
+      0xfe00 ..= 0xfe9f if self.dma.active => 0xff,
+      0x0000 ..= 0x7fff | 0xa000 ..= 0xbfff => self.cartridge.read(addr),
+      0x8000 ..= 0x9fff if self.vram_lock => 0xff,
+      0x8000 ..= 0x9fff => self.ppu.vram[(addr - 0x8000) as usize],
       0xc000 ..= 0xdfff => self.wram[(addr - 0xc000) as usize],
-      0xfe00 ..= 0xfe9f => self.oam[(addr - 0xfe00) as usize],
+      0xfe00 ..= 0xfe9f if self.oam_lock => 0xff,
+      0xfe00 ..= 0xfe9f => self.ppu.oam[(addr - 0xfe00) as usize],
 
-      0xff04 => self.timer.div.to_be_bytes()[0],
-      0xff05 => self.timer.tima,
-      0xff06 => self.timer.tma,
-      0xff07 => self.timer.tac,
-
-      0xff40 => self.lcd.ctrl.bits(),
-      0xff41 => self.lcd.stat.bits(),
-      0xff42 => self.lcd.scroll.1,
-      0xff43 => self.lcd.scroll.0,
-      0xff44 => self.lcd.ly,
-      0xff45 => self.lcd.lyc,
-      0xff46 => self.dma.source.to_be_bytes()[0],
-      0xff47 => self.lcd.bg_palette,
-      0xff48 => self.lcd.obj_palette0,
-      0xff49 => self.lcd.obj_palette1,
-      0xff4a => self.lcd.window.1,
-      0xff4b => self.lcd.window.0,
-
-      0xff0f => self.if_reg.bits(),
       0xffff => self.ie_reg.bits(),
 
-      IO_REGISTERS_START ..= IO_REGISTERS_END => self.io_regs[(addr -  IO_REGISTERS_START) as usize],
+      IO_REGISTERS_START ..= IO_REGISTERS_END => self.io_read(addr),
       0xff80 ..= 0xfffe => self.hram[(addr - 0xff80) as usize],
 
       _ => { eprintln!("Addressing not implemented for address {addr:#04x}"); 0 }
@@ -140,39 +183,98 @@ impl BUS {
 
 
   pub fn mem_write(&mut self, addr: u16, data: u8) {
+    if self.dma.conflicts_with(addr) { return; }
+
     match addr {
-      0x0000 ..= 0x7fff => eprintln!("Trying to write ROM memory at {addr:#04x}."),
-      0x8000 ..= 0x9fff => self.vram[(addr - 0x8000) as usize] = data,
-      0xa000 ..= 0xbfff => eprintln!("EXT RAM address range not implemented."),
+      0xfe00 ..= 0xfe9f if self.dma.active => {},
+      0x0000 ..= 0x7fff | 0xa000 ..= 0xbfff => self.cartridge.write(addr, data),
+      0x8000 ..= 0x9fff if self.vram_lock => {},
+      0x8000 ..= 0x9fff => self.ppu.vram[(addr - 0x8000) as usize] = data,
       0xc000 ..= 0xdfff => self.wram[(addr - 0xc000) as usize] = data,
-      0xfe00 ..= 0xfe9f => self.oam[(addr - 0xfe00) as usize] = data,
-
+      0xfe00 ..= 0xfe9f if self.oam_lock => {},
+      0xfe00 ..= 0xfe9f => self.ppu.oam[(addr - 0xfe00) as usize] = data,
 
-      0xff04 => self.timer.div = 0,
-      0xff05 => self.timer.tima = data,
-      0xff06 => self.timer.tma = data,
-      0xff07 => self.timer.tac = data,
-      
-      0xff40 => self.lcd.ctrl = LCDControl::new(data),
-      //0xff41 => { todo!("not all bits of lcdstate can be written") },
-      0xff42 => self.lcd.scroll.1 = data,
-      0xff43 => self.lcd.scroll.0 = data,
-      0xff45 => self.lcd.lyc = data,
-      0xff46 => self.dma.write(data),
-      0xff47 => self.lcd.bg_palette = data,
-      0xff48 => self.lcd.obj_palette0 = data,
-      0xff49 => self.lcd.obj_palette1 = data,
-      0xff4a => self.lcd.window.1 = data,
-      0xff4b => self.lcd.window.0 = data,
-
-      0xff0f => self.if_reg = InterruptRegister::new(data),
       0xffff => self.ie_reg = InterruptRegister::new(data),
 
-      IO_REGISTERS_START ..= IO_REGISTERS_END => self.io_regs[(addr -  IO_REGISTERS_START) as usize] = data,
+      IO_REGISTERS_START ..= IO_REGISTERS_END => self.io_write(addr, data),
       0xff80 ..= 0xfffe => self.hram[(addr - 0xff80) as usize] = data,
 
       _ => { eprintln!("Addressing not implemented for address {addr:#04x}"); }
     };
   }
+
+  fn io_read(&self, addr: u16) -> u8 {
+    match addr {
+      0xff00 => self.joypad.read(),
+      0xff01 => self.serial.data,
+      0xff02 => self.serial.read_control(),
+
+      0xff04 => self.timer.div.to_be_bytes()[0],
+      0xff05 => self.timer.tima,
+      0xff06 => self.timer.tma,
+      0xff07 => self.timer.read_tac(),
+
+      0xff10 ..= 0xff3f => self.apu.read(addr),
+
+      0xff40 => self.ppu.lcd.ctrl.bits(),
+      // bit 7 is unused and always reads 1
+      0xff41 => self.ppu.lcd.stat.bits() | 0x80,
+      0xff42 => self.ppu.lcd.scroll.1,
+      0xff43 => self.ppu.lcd.scroll.0,
+      0xff44 => self.ppu.lcd.ly,
+      0xff45 => self.ppu.lcd.lyc,
+      0xff46 => self.dma.register,
+      0xff47 => self.ppu.lcd.bg_palette,
+      0xff48 => self.ppu.lcd.obj_palette0,
+      0xff49 => self.ppu.lcd.obj_palette1,
+      0xff4a => self.ppu.lcd.window.1,
+      0xff4b => self.ppu.lcd.window.0,
+
+      0xff0f => self.if_reg.bits(),
+
+      _ => self.io_regs[(addr - IO_REGISTERS_START) as usize],
+    }
+  }
+
+  fn io_write(&mut self, addr: u16, data: u8) {
+    match addr {
+      0xff00 => if self.joypad.write(data) {
+        self.if_reg.insert(InterruptRegister::JOYPAD);
+      }
+      0xff01 => self.serial.data = data,
+      0xff02 => self.serial.write_control(data),
+
+      0xff04 => {
+        // resetting DIV can produce a falling edge for the frame sequencer
+        if self.timer.div & 0x1000 != 0 { self.apu.clock_frame_sequencer(); }
+        self.timer.write_div();
+      }
+      0xff05 => self.timer.write_tima(data),
+      0xff06 => self.timer.write_tma(data),
+      0xff07 => self.timer.write_tac(data),
+      
+      0xff10 ..= 0xff3f => self.apu.write(addr, data),
+
+      0xff40 => self.ppu.lcd.ctrl = LCDControl::new(data),
+      // the mode and LY=LYC bits are read only
+      0xff41 => {
+        let read_only = self.ppu.lcd.stat & (LCDStatus::PPU_MODE | LCDStatus::LYC_EQ_LY);
+        self.ppu.lcd.stat = LCDStatus::new(data & 0x78) | read_only;
+      }
+      0xff42 => self.ppu.lcd.scroll.1 = data,
+      0xff43 => self.ppu.lcd.scroll.0 = data,
+      0xff45 => self.ppu.lcd.lyc = data,
+      0xff46 => self.dma.write(data),
+      0xff47 => self.ppu.lcd.bg_palette = data,
+      0xff48 => self.ppu.lcd.obj_palette0 = data,
+      0xff49 => self.ppu.lcd.obj_palette1 = data,
+      0xff4a => self.ppu.lcd.window.1 = data,
+      0xff4b => self.ppu.lcd.window.0 = data,
+
+      0xff0f => self.if_reg = InterruptRegister::new(data),
+
+      _ => self.io_regs[(addr - IO_REGISTERS_START) as usize] = data,
+    }
+  }
 }
 
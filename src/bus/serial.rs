@@ -0,0 +1,136 @@
+use std::{cell::RefCell, rc::{Rc, Weak}};
+
+use crate::definitions::CLOCK_SPEED;
+use super::{BUS, SerialControl};
+
+// The internal clock shifts a bit at 8192 Hz.
+const CYCLES_PER_BIT: usize = CLOCK_SPEED / 8192;
+
+// What is plugged in the link port.
+pub trait SerialDevice {
+  // Called when this side clocks out a byte, returns the one shifted in at the same time.
+  fn exchange(&mut self, data: u8) -> u8;
+}
+
+// Nothing connected: the input line is pulled high.
+pub struct NullDevice;
+
+impl SerialDevice for NullDevice {
+  fn exchange(&mut self, _data: u8) -> u8 { 0xff }
+}
+
+// Keeps every byte sent, test roms like Blargg's print their results this way.
+// Clones share the same buffer, so one can be kept to read what the other received.
+#[derive(Clone, Default)]
+pub struct CaptureDevice {
+  pub data: Rc<RefCell<Vec<u8>>>,
+}
+
+impl CaptureDevice {
+  pub fn new() -> Self { Self::default() }
+
+  pub fn output(&self) -> String {
+    String::from_utf8_lossy(&self.data.borrow()).into_owned()
+  }
+}
+
+impl SerialDevice for CaptureDevice {
+  fn exchange(&mut self, data: u8) -> u8 {
+    self.data.borrow_mut().push(data);
+    0xff
+  }
+}
+
+// The link port of another emulator in the same process.
+pub struct LinkedDevice {
+  bus: Weak<RefCell<BUS>>,
+}
+
+impl LinkedDevice {
+  pub fn new(bus: &Rc<RefCell<BUS>>) -> Self {
+    LinkedDevice { bus: Rc::downgrade(bus) }
+  }
+}
+
+impl SerialDevice for LinkedDevice {
+  fn exchange(&mut self, data: u8) -> u8 {
+    match self.bus.upgrade() {
+      Some(bus) => bus.borrow_mut().serial_receive(data),
+      None => 0xff,
+    }
+  }
+}
+
+// https://gbdev.io/pandocs/Serial_Data_Transfer_(Link_Cable).html
+pub struct Serial {
+  pub data: u8,
+  pub control: SerialControl,
+  pub device: Box<dyn SerialDevice>,
+
+  // bits left to shift in the current internal clock transfer
+  bits: u8,
+  cycles: usize,
+}
+
+impl Default for Serial {
+  fn default() -> Self { Self::new() }
+}
+
+impl Serial {
+  pub fn new() -> Self {
+    Serial {
+      data: 0,
+      control: SerialControl::empty(),
+      device: Box::new(NullDevice),
+      bits: 0,
+      cycles: 0,
+    }
+  }
+
+  // the unused bits read as 1
+  pub fn read_control(&self) -> u8 {
+    self.control.bits() | 0x7e
+  }
+
+  pub fn write_control(&mut self, data: u8) {
+    self.control = SerialControl::new(data);
+    if self.control.contains(SerialControl::TRANSFER_ENABLE) {
+      self.bits = 8;
+      self.cycles = 0;
+    }
+  }
+
+  // Returns true when an internal clock transfer completes, requesting the serial interrupt.
+  // With the external clock nothing happens until the other side sends a byte.
+  pub fn step(&mut self, cycles: usize) -> bool {
+    if !self.control.contains(SerialControl::TRANSFER_ENABLE | SerialControl::CLOCK_SELECT) {
+      return false;
+    }
+
+    self.cycles += cycles;
+    while self.cycles >= CYCLES_PER_BIT && self.bits > 0 {
+      self.cycles -= CYCLES_PER_BIT;
+      self.bits -= 1;
+    }
+    if self.bits > 0 { return false; }
+
+    // both ends swap their whole register by the end of the transfer
+    self.data = self.device.exchange(self.data);
+    self.control.remove(SerialControl::TRANSFER_ENABLE);
+    true
+  }
+
+  // The other side clocked a byte in. Only a transfer started with the external
+  // clock takes it, otherwise the line reads high. Returns the byte sent back and
+  // whether the transfer completed.
+  pub fn receive(&mut self, data: u8) -> (u8, bool) {
+    let waiting = self.control.contains(SerialControl::TRANSFER_ENABLE)
+      && !self.control.contains(SerialControl::CLOCK_SELECT);
+    if !waiting { return (0xff, false); }
+
+    let sent = self.data;
+    self.data = data;
+    self.control.remove(SerialControl::TRANSFER_ENABLE);
+    (sent, true)
+  }
+}
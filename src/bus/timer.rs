@@ -2,59 +2,117 @@ use log::info;
 
 use crate::definitions::DIV_INIT;
 
+// T-cycles between a TIMA overflow and the TMA reload, an M-cycle.
+const RELOAD_DELAY: u8 = 4;
+
+// https://gbdev.io/pandocs/Timer_Obscure_Behaviour.html
 pub struct Timer {
+  // the internal 16 bit counter, DIV is its upper byte
   pub div: u16,
   pub tima: u8,
   pub tma: u8,
   pub tac: u8,
-  pub cycles: usize,
+
+  // cycles left until TMA is loaded into TIMA after an overflow
+  reload_delay: u8,
+  // cycles left of the M-cycle in which TMA was loaded
+  reloading: u8,
 }
 
 impl Timer {
   pub fn new() -> Self {
     Timer {
-      div: DIV_INIT, tima: 0, tma: 0, tac: 0, cycles: 0
+      div: DIV_INIT, tima: 0, tma: 0, tac: 0,
+      reload_delay: 0, reloading: 0,
     }
   }
 
+  // Returns true when TMA is reloaded, requesting the timer interrupt.
   pub fn step(&mut self, cycles: usize) -> bool {
-    self.div = self.div.wrapping_add(cycles as u16);
-
-    let mut overflowed: bool = false;
-    
-    if self.is_timer_enabled() {
-      info!("[Timer] Increasing cycles");
-      self.cycles = self.cycles.wrapping_add(cycles);
-
-      if self.cycles > self.get_frequency() {
-        info!("[Timer] Increasing registers");
+    let mut interrupt = false;
 
-        self.cycles = self.cycles
-          .wrapping_sub(self.get_frequency());
-        
-        (self.tima, overflowed) = self.tima.overflowing_add(1);
-
-        if overflowed {
-          info!("[Timer] TIMA overflowed.");
+    for _ in 0..cycles {
+      self.reloading = self.reloading.saturating_sub(1);
+      if self.reload_delay > 0 {
+        self.reload_delay -= 1;
+        if self.reload_delay == 0 {
+          info!("[Timer] TIMA reloaded.");
           self.tima = self.tma;
+          self.reloading = RELOAD_DELAY;
+          interrupt = true;
         }
       }
+
+      let input = self.input();
+      self.div = self.div.wrapping_add(1);
+      if input && !self.input() { self.increment(); }
     }
 
-    overflowed
+    interrupt
+  }
+
+  // Resetting the counter can produce a falling edge too.
+  pub fn write_div(&mut self) {
+    let input = self.input();
+    self.div = 0;
+    if input { self.increment(); }
+  }
+
+  // A write while the overflowed TIMA is still 0 cancels the reload,
+  // a write in the same cycle as the reload is lost.
+  pub fn write_tima(&mut self, data: u8) {
+    if self.reloading > 0 { return; }
+    self.reload_delay = 0;
+    self.tima = data;
+  }
+
+  pub fn write_tma(&mut self, data: u8) {
+    self.tma = data;
+    if self.reloading > 0 { self.tima = data; }
+  }
+
+  // Disabling the timer or selecting another bit can increment TIMA, on DMG.
+  pub fn write_tac(&mut self, data: u8) {
+    let input = self.input();
+    self.tac = data & 0b111;
+    if input && !self.input() { self.increment(); }
+  }
+
+  // the unused bits read as 1
+  pub fn read_tac(&self) -> u8 {
+    self.tac | 0xf8
+  }
+
+  fn increment(&mut self) {
+    let overflowed;
+    (self.tima, overflowed) = self.tima.overflowing_add(1);
+
+    if overflowed {
+      info!("[Timer] TIMA overflowed.");
+      self.reload_delay = RELOAD_DELAY;
+    }
+  }
+
+  // TIMA is clocked by the falling edge of this signal.
+  fn input(&self) -> bool {
+    self.is_timer_enabled() && self.div & self.get_frequency_bit() != 0
   }
 
   pub fn is_timer_enabled(&self) -> bool {
     self.tac & 0b0100 != 0
   }
 
-  pub fn get_frequency(&self) -> usize {
+  // The bit of the counter that clocks TIMA, TIMA counts at half its frequency.
+  pub fn get_frequency_bit(&self) -> u16 {
     match self.tac & 0b0011 {
-      0b00 => 1024,
-      0b01 => 16,
-      0b10 => 64,
-      0b11 => 256,
-      _ => panic!("TAC register bad set")
+      0b00 => 1 << 9,
+      0b01 => 1 << 3,
+      0b10 => 1 << 5,
+      _ => 1 << 7,
     }
   }
-}
\ No newline at end of file
+}
+
+impl Default for Timer {
+  fn default() -> Self { Self::new() }
+}
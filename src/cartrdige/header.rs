@@ -0,0 +1,710 @@
+use std::fmt;
+
+use super::{ROM_BANK_SIZE, NINTENDO_LOGO};
+
+// https://gbdev.io/pandocs/The_Cartridge_Header.html
+pub const HEADER_END: usize = 0x150;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HeaderError {
+  TooSmall(usize),
+  UnknownCartType(u8),
+  UnknownRomSize(u8),
+  UnknownRamSize(u8),
+  InvalidLogo,
+  HeaderChecksum { expected: u8, computed: u8 },
+  GlobalChecksum { expected: u16, computed: u16 },
+}
+
+impl fmt::Display for HeaderError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      HeaderError::TooSmall(len) => write!(f, "Rom is too small ({len} bytes), and doesn't contain a full header."),
+      HeaderError::UnknownCartType(code) => write!(f, "Unknown cartridge type {code:#04x}."),
+      HeaderError::UnknownRomSize(code) => write!(f, "Unknown ROM size {code:#04x}."),
+      HeaderError::UnknownRamSize(code) => write!(f, "Unknown RAM size {code:#04x}."),
+      HeaderError::InvalidLogo => write!(f, "The Nintendo logo doesn't match."),
+      HeaderError::HeaderChecksum { expected, computed } =>
+        write!(f, "Header checksum mismatch: expected {expected:#04x}, computed {computed:#04x}."),
+      HeaderError::GlobalChecksum { expected, computed } =>
+        write!(f, "Global checksum mismatch: expected {expected:#06x}, computed {computed:#06x}."),
+    }
+  }
+}
+
+impl std::error::Error for HeaderError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CartType {
+  RomOnly,
+  MBC1,
+  MBC1Ram,
+  MBC1RamBattery,
+  MBC2,
+  MBC2Battery,
+  RomRam,
+  RomRamBattery,
+  MMM01,
+  MMM01Ram,
+  MMM01RamBattery,
+  MBC3TimerBattery,
+  MBC3TimerRamBattery,
+  MBC3,
+  MBC3Ram,
+  MBC3RamBattery,
+  MBC5,
+  MBC5Ram,
+  MBC5RamBattery,
+  MBC5Rumble,
+  MBC5RumbleRam,
+  MBC5RumbleRamBattery,
+  MBC6,
+  MBC7SensorRumbleRamBattery,
+  PocketCamera,
+  BandaiTama5,
+  HuC3,
+  HuC1RamBattery,
+}
+
+use CartType::*;
+
+impl CartType {
+  pub fn new(code: u8) -> Result<Self, HeaderError> {
+    let cart_type = match code {
+      0x00 => RomOnly,
+      0x01 => MBC1,
+      0x02 => MBC1Ram,
+      0x03 => MBC1RamBattery,
+      0x05 => MBC2,
+      0x06 => MBC2Battery,
+      0x08 => RomRam,
+      0x09 => RomRamBattery,
+      0x0b => MMM01,
+      0x0c => MMM01Ram,
+      0x0d => MMM01RamBattery,
+      0x0f => MBC3TimerBattery,
+      0x10 => MBC3TimerRamBattery,
+      0x11 => MBC3,
+      0x12 => MBC3Ram,
+      0x13 => MBC3RamBattery,
+      0x19 => MBC5,
+      0x1a => MBC5Ram,
+      0x1b => MBC5RamBattery,
+      0x1c => MBC5Rumble,
+      0x1d => MBC5RumbleRam,
+      0x1e => MBC5RumbleRamBattery,
+      0x20 => MBC6,
+      0x22 => MBC7SensorRumbleRamBattery,
+      0xfc => PocketCamera,
+      0xfd => BandaiTama5,
+      0xfe => HuC3,
+      0xff => HuC1RamBattery,
+      _ => return Err(HeaderError::UnknownCartType(code)),
+    };
+
+    Ok(cart_type)
+  }
+
+  pub fn name(&self) -> &'static str {
+    match self {
+      RomOnly => "ROM ONLY",
+      MBC1 => "MBC1",
+      MBC1Ram => "MBC1+RAM",
+      MBC1RamBattery => "MBC1+RAM+BATTERY",
+      MBC2 => "MBC2",
+      MBC2Battery => "MBC2+BATTERY",
+      RomRam => "ROM+RAM",
+      RomRamBattery => "ROM+RAM+BATTERY",
+      MMM01 => "MMM01",
+      MMM01Ram => "MMM01+RAM",
+      MMM01RamBattery => "MMM01+RAM+BATTERY",
+      MBC3TimerBattery => "MBC3+TIMER+BATTERY",
+      MBC3TimerRamBattery => "MBC3+TIMER+RAM+BATTERY",
+      MBC3 => "MBC3",
+      MBC3Ram => "MBC3+RAM",
+      MBC3RamBattery => "MBC3+RAM+BATTERY",
+      MBC5 => "MBC5",
+      MBC5Ram => "MBC5+RAM",
+      MBC5RamBattery => "MBC5+RAM+BATTERY",
+      MBC5Rumble => "MBC5+RUMBLE",
+      MBC5RumbleRam => "MBC5+RUMBLE+RAM",
+      MBC5RumbleRamBattery => "MBC5+RUMBLE+RAM+BATTERY",
+      MBC6 => "MBC6",
+      MBC7SensorRumbleRamBattery => "MBC7+SENSOR+RUMBLE+RAM+BATTERY",
+      PocketCamera => "POCKET CAMERA",
+      BandaiTama5 => "BANDAI TAMA5",
+      HuC3 => "HuC3",
+      HuC1RamBattery => "HuC1+RAM+BATTERY",
+    }
+  }
+
+  pub fn has_battery(&self) -> bool {
+    matches!(self,
+      MBC1RamBattery | MBC2Battery | RomRamBattery | MMM01RamBattery |
+      MBC3TimerBattery | MBC3TimerRamBattery | MBC3RamBattery |
+      MBC5RamBattery | MBC5RumbleRamBattery | MBC7SensorRumbleRamBattery |
+      HuC3 | HuC1RamBattery
+    )
+  }
+
+  pub fn has_timer(&self) -> bool {
+    matches!(self, MBC3TimerBattery | MBC3TimerRamBattery)
+  }
+
+  pub fn has_rumble(&self) -> bool {
+    matches!(self, MBC5Rumble | MBC5RumbleRam | MBC5RumbleRamBattery | MBC7SensorRumbleRamBattery)
+  }
+}
+
+impl fmt::Display for CartType {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}", self.name())
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RomSize {
+  Kib32, Kib64, Kib128, Kib256, Kib512, Mib1, Mib2, Mib4, Mib8,
+  Mib1_1, Mib1_2, Mib1_5,
+}
+
+impl RomSize {
+  pub fn new(code: u8) -> Result<Self, HeaderError> {
+    let size = match code {
+      0x00 => RomSize::Kib32,
+      0x01 => RomSize::Kib64,
+      0x02 => RomSize::Kib128,
+      0x03 => RomSize::Kib256,
+      0x04 => RomSize::Kib512,
+      0x05 => RomSize::Mib1,
+      0x06 => RomSize::Mib2,
+      0x07 => RomSize::Mib4,
+      0x08 => RomSize::Mib8,
+      0x52 => RomSize::Mib1_1,
+      0x53 => RomSize::Mib1_2,
+      0x54 => RomSize::Mib1_5,
+      _ => return Err(HeaderError::UnknownRomSize(code)),
+    };
+
+    Ok(size)
+  }
+
+  pub fn banks(&self) -> usize {
+    match self {
+      RomSize::Kib32 => 2,
+      RomSize::Kib64 => 4,
+      RomSize::Kib128 => 8,
+      RomSize::Kib256 => 16,
+      RomSize::Kib512 => 32,
+      RomSize::Mib1 => 64,
+      RomSize::Mib2 => 128,
+      RomSize::Mib4 => 256,
+      RomSize::Mib8 => 512,
+      RomSize::Mib1_1 => 72,
+      RomSize::Mib1_2 => 80,
+      RomSize::Mib1_5 => 96,
+    }
+  }
+
+  pub fn bytes(&self) -> usize {
+    self.banks() * ROM_BANK_SIZE
+  }
+
+  pub fn name(&self) -> &'static str {
+    match self {
+      RomSize::Kib32 => "32 KiB",
+      RomSize::Kib64 => "64 KiB",
+      RomSize::Kib128 => "128 KiB",
+      RomSize::Kib256 => "256 KiB",
+      RomSize::Kib512 => "512 KiB",
+      RomSize::Mib1 => "1 MiB",
+      RomSize::Mib2 => "2 MiB",
+      RomSize::Mib4 => "4 MiB",
+      RomSize::Mib8 => "8 MiB",
+      RomSize::Mib1_1 => "1.1 MiB",
+      RomSize::Mib1_2 => "1.2 MiB",
+      RomSize::Mib1_5 => "1.5 MiB",
+    }
+  }
+}
+
+impl fmt::Display for RomSize {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}", self.name())
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RamSize {
+  None, Kib2, Kib8, Kib32, Kib64, Kib128,
+}
+
+impl RamSize {
+  pub fn new(code: u8) -> Result<Self, HeaderError> {
+    let size = match code {
+      0x00 => RamSize::None,
+      0x01 => RamSize::Kib2,
+      0x02 => RamSize::Kib8,
+      0x03 => RamSize::Kib32,
+      0x04 => RamSize::Kib128,
+      0x05 => RamSize::Kib64,
+      _ => return Err(HeaderError::UnknownRamSize(code)),
+    };
+
+    Ok(size)
+  }
+
+  pub fn bytes(&self) -> usize {
+    match self {
+      RamSize::None => 0,
+      RamSize::Kib2 => 2 * 1024,
+      RamSize::Kib8 => 8 * 1024,
+      RamSize::Kib32 => 32 * 1024,
+      RamSize::Kib64 => 64 * 1024,
+      RamSize::Kib128 => 128 * 1024,
+    }
+  }
+
+  pub fn name(&self) -> &'static str {
+    match self {
+      RamSize::None => "None",
+      RamSize::Kib2 => "2 KiB",
+      RamSize::Kib8 => "8 KiB",
+      RamSize::Kib32 => "32 KiB",
+      RamSize::Kib64 => "64 KiB",
+      RamSize::Kib128 => "128 KiB",
+    }
+  }
+}
+
+impl fmt::Display for RamSize {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}", self.name())
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Destination {
+  Japan,
+  Overseas,
+  Unknown(u8),
+}
+
+impl Destination {
+  pub fn new(code: u8) -> Self {
+    match code {
+      0x00 => Destination::Japan,
+      0x01 => Destination::Overseas,
+      _ => Destination::Unknown(code),
+    }
+  }
+
+  pub fn name(&self) -> &'static str {
+    match self {
+      Destination::Japan => "Japan",
+      Destination::Overseas => "Overseas",
+      Destination::Unknown(_) => "Unknown",
+    }
+  }
+}
+
+impl fmt::Display for Destination {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}", self.name())
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CgbSupport {
+  None,
+  Compatible,
+  Only,
+}
+
+// The old licensee code 0x33 means the new two characters code at 0x144 is used instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Licensee {
+  Old(u8),
+  New([u8; 2]),
+}
+
+impl Licensee {
+  pub fn new(old_code: u8, new_code: [u8; 2]) -> Self {
+    if old_code == 0x33 { Licensee::New(new_code) }
+    else { Licensee::Old(old_code) }
+  }
+
+  pub fn name(&self) -> &'static str {
+    let name = match self {
+      Licensee::Old(code) => old_licensee_name(*code),
+      Licensee::New(code) => new_licensee_name(code),
+    };
+
+    name.unwrap_or("Unknown")
+  }
+}
+
+impl fmt::Display for Licensee {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}", self.name())
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct CartridgeData {
+  pub title: String,
+  pub manufacturer: Option<String>,
+  pub cgb: CgbSupport,
+  pub sgb: bool,
+  pub cart_type: CartType,
+  pub rom_size: RomSize,
+  pub ram_size: RamSize,
+  pub destination: Destination,
+  pub licensee: Licensee,
+  pub version: u8,
+
+  pub logo_valid: bool,
+  pub header_checksum: u8,
+  pub computed_header_checksum: u8,
+  pub global_checksum: u16,
+  pub computed_global_checksum: u16,
+}
+
+// Titles are upper case ASCII, padded with zeros. Anything else gets replaced,
+// so that japanese titles or garbage don't break the parsing.
+fn extract_string(data: &[u8]) -> String {
+  data.iter()
+    .take_while(|&&byte| byte != 0)
+    .map(|&byte| if byte.is_ascii_graphic() || byte == b' ' { byte as char } else { '?' })
+    .collect::<String>()
+    .trim_end()
+    .to_string()
+}
+
+impl CartridgeData {
+  pub fn new(data: &[u8]) -> Result<Self, HeaderError> {
+    if data.len() < HEADER_END {
+      return Err(HeaderError::TooSmall(data.len()));
+    }
+
+    let cgb = match data[0x143] {
+      0x80 => CgbSupport::Compatible,
+      0xc0 => CgbSupport::Only,
+      _ => CgbSupport::None,
+    };
+
+    // On newer cartridges the title got shorter, and its last 4 bytes hold
+    // the manufacturer code instead. Older ones just use the whole range for the title.
+    let code = &data[0x13f .. 0x143];
+    let has_manufacturer = cgb != CgbSupport::None
+      && code.iter().all(|byte| byte.is_ascii_uppercase() || byte.is_ascii_digit());
+    let (title, manufacturer) = if has_manufacturer {
+      (extract_string(&data[0x134 .. 0x13f]), Some(extract_string(code)))
+    } else {
+      (extract_string(&data[0x134 .. 0x144]), None)
+    };
+
+    let cart_type = CartType::new(data[0x147])?;
+    let rom_size = RomSize::new(data[0x148])?;
+    let ram_size = RamSize::new(data[0x149])?;
+    let destination = Destination::new(data[0x14a]);
+    let licensee = Licensee::new(data[0x14b], [data[0x144], data[0x145]]);
+    let sgb = data[0x146] == 0x03;
+    let version = data[0x14c];
+
+    let logo_valid = data[0x104 .. 0x134] == NINTENDO_LOGO;
+    let header_checksum = data[0x14d];
+    let computed_header_checksum = compute_header_checksum(data);
+    let global_checksum = u16::from_be_bytes([data[0x14e], data[0x14f]]);
+    let computed_global_checksum = compute_global_checksum(data);
+
+    Ok(CartridgeData {
+      title,
+      manufacturer,
+      cgb,
+      sgb,
+      cart_type,
+      rom_size,
+      ram_size,
+      destination,
+      licensee,
+      version,
+      logo_valid,
+      header_checksum,
+      computed_header_checksum,
+      global_checksum,
+      computed_global_checksum,
+    })
+  }
+
+  // The parsing is lenient on the logo and the checksums, as homebrew and test roms often get them wrong.
+  // The boot rom refuses to start with a bad logo or header checksum, while the global one is never checked.
+  pub fn validate(&self) -> Result<(), HeaderError> {
+    if !self.logo_valid {
+      return Err(HeaderError::InvalidLogo);
+    }
+    if !self.header_checksum_valid() {
+      return Err(HeaderError::HeaderChecksum {
+        expected: self.header_checksum, computed: self.computed_header_checksum
+      });
+    }
+    if !self.global_checksum_valid() {
+      return Err(HeaderError::GlobalChecksum {
+        expected: self.global_checksum, computed: self.computed_global_checksum
+      });
+    }
+
+    Ok(())
+  }
+
+  pub fn header_checksum_valid(&self) -> bool {
+    self.header_checksum == self.computed_header_checksum
+  }
+
+  pub fn global_checksum_valid(&self) -> bool {
+    self.global_checksum == self.computed_global_checksum
+  }
+
+  pub fn has_battery(&self) -> bool {
+    self.cart_type.has_battery()
+  }
+}
+
+pub fn compute_header_checksum(data: &[u8]) -> u8 {
+  data[0x134 ..= 0x14c]
+    .iter()
+    .fold(0u8, |check, &byte| check.wrapping_sub(byte).wrapping_sub(1))
+}
+
+pub fn compute_global_checksum(data: &[u8]) -> u16 {
+  data.iter()
+    .enumerate()
+    .filter(|(i, _)| *i != 0x14e && *i != 0x14f)
+    .fold(0u16, |check, (_, &byte)| check.wrapping_add(byte as u16))
+}
+
+fn new_licensee_name(code: &[u8; 2]) -> Option<&'static str> {
+  let name = match code {
+    b"00" => "None",
+    b"01" => "Nintendo Research & Development 1",
+    b"08" => "Capcom",
+    b"13" => "EA (Electronic Arts)",
+    b"18" => "Hudson Soft",
+    b"19" => "B-AI",
+    b"20" => "KSS",
+    b"22" => "Planning Office WADA",
+    b"24" => "PCM Complete",
+    b"25" => "San-X",
+    b"28" => "Kemco",
+    b"29" => "SETA Corporation",
+    b"30" => "Viacom",
+    b"31" => "Nintendo",
+    b"32" => "Bandai",
+    b"33" => "Ocean Software/Acclaim Entertainment",
+    b"34" => "Konami",
+    b"35" => "HectorSoft",
+    b"37" => "Taito",
+    b"38" => "Hudson Soft",
+    b"39" => "Banpresto",
+    b"41" => "Ubi Soft",
+    b"42" => "Atlus",
+    b"44" => "Malibu Interactive",
+    b"46" => "Angel",
+    b"47" => "Bullet-Proof Software",
+    b"49" => "Irem",
+    b"50" => "Absolute",
+    b"51" => "Acclaim Entertainment",
+    b"52" => "Activision",
+    b"53" => "Sammy USA Corporation",
+    b"54" => "Konami",
+    b"55" => "Hi Tech Expressions",
+    b"56" => "LJN",
+    b"57" => "Matchbox",
+    b"58" => "Mattel",
+    b"59" => "Milton Bradley Company",
+    b"60" => "Titus Interactive",
+    b"61" => "Virgin Games Ltd.",
+    b"64" => "Lucasfilm Games",
+    b"67" => "Ocean Software",
+    b"69" => "EA (Electronic Arts)",
+    b"70" => "Infogrames",
+    b"71" => "Interplay Entertainment",
+    b"72" => "Broderbund",
+    b"73" => "Sculptured Software",
+    b"75" => "The Sales Curve Limited",
+    b"78" => "THQ",
+    b"79" => "Accolade",
+    b"80" => "Misawa Entertainment",
+    b"83" => "LOZC G.",
+    b"86" => "Tokuma Shoten",
+    b"87" => "Tsukuda Original",
+    b"91" => "Chunsoft Co.",
+    b"92" => "Video System",
+    b"93" => "Ocean Software/Acclaim Entertainment",
+    b"95" => "Varie",
+    b"96" => "Yonezawa/S'Pal",
+    b"97" => "Kaneko",
+    b"99" => "Pack-In-Video",
+    b"9H" => "Bottom Up",
+    b"A4" => "Konami (Yu-Gi-Oh!)",
+    b"BL" => "MTO",
+    b"DK" => "Kodansha",
+    _ => return None,
+  };
+
+  Some(name)
+}
+
+fn old_licensee_name(code: u8) -> Option<&'static str> {
+  let name = match code {
+    0x00 => "None",
+    0x01 => "Nintendo",
+    0x08 => "Capcom",
+    0x09 => "HOT-B",
+    0x0a => "Jaleco",
+    0x0b => "Coconuts Japan",
+    0x0c => "Elite Systems",
+    0x13 => "EA (Electronic Arts)",
+    0x18 => "Hudson Soft",
+    0x19 => "ITC Entertainment",
+    0x1a => "Yanoman",
+    0x1d => "Japan Clary",
+    0x1f => "Virgin Games Ltd.",
+    0x24 => "PCM Complete",
+    0x25 => "San-X",
+    0x28 => "Kemco",
+    0x29 => "SETA Corporation",
+    0x30 => "Infogrames",
+    0x31 => "Nintendo",
+    0x32 => "Bandai",
+    0x34 => "Konami",
+    0x35 => "HectorSoft",
+    0x38 => "Capcom",
+    0x39 => "Banpresto",
+    0x3c => "Entertainment Interactive",
+    0x3e => "Gremlin",
+    0x41 => "Ubi Soft",
+    0x42 => "Atlus",
+    0x44 => "Malibu Interactive",
+    0x46 => "Angel",
+    0x47 => "Spectrum HoloByte",
+    0x49 => "Irem",
+    0x4a => "Virgin Games Ltd.",
+    0x4d => "Malibu Interactive",
+    0x4f => "U.S. Gold",
+    0x50 => "Absolute",
+    0x51 => "Acclaim Entertainment",
+    0x52 => "Activision",
+    0x53 => "Sammy USA Corporation",
+    0x54 => "GameTek",
+    0x55 => "Park Place",
+    0x56 => "LJN",
+    0x57 => "Matchbox",
+    0x59 => "Milton Bradley Company",
+    0x5a => "Mindscape",
+    0x5b => "Romstar",
+    0x5c => "Naxat Soft",
+    0x5d => "Tradewest",
+    0x60 => "Titus Interactive",
+    0x61 => "Virgin Games Ltd.",
+    0x67 => "Ocean Software",
+    0x69 => "EA (Electronic Arts)",
+    0x6e => "Elite Systems",
+    0x6f => "Electro Brain",
+    0x70 => "Infogrames",
+    0x71 => "Interplay Entertainment",
+    0x72 => "Broderbund",
+    0x73 => "Sculptured Software",
+    0x75 => "The Sales Curve Limited",
+    0x78 => "THQ",
+    0x79 => "Accolade",
+    0x7a => "Triffix Entertainment",
+    0x7c => "MicroProse",
+    0x7f => "Kemco",
+    0x80 => "Misawa Entertainment",
+    0x83 => "LOZC G.",
+    0x86 => "Tokuma Shoten",
+    0x8b => "Bullet-Proof Software",
+    0x8c => "Vic Tokai Corp.",
+    0x8e => "Ape Inc.",
+    0x8f => "I'Max",
+    0x91 => "Chunsoft Co.",
+    0x92 => "Video System",
+    0x93 => "Tsubaraya Productions",
+    0x95 => "Varie",
+    0x96 => "Yonezawa/S'Pal",
+    0x97 => "Kemco",
+    0x99 => "Arc",
+    0x9a => "Nihon Bussan",
+    0x9b => "Tecmo",
+    0x9c => "Imagineer",
+    0x9d => "Banpresto",
+    0x9f => "Nova",
+    0xa1 => "Hori Electric",
+    0xa2 => "Bandai",
+    0xa4 => "Konami",
+    0xa6 => "Kawada",
+    0xa7 => "Takara",
+    0xa9 => "Technos Japan",
+    0xaa => "Broderbund",
+    0xac => "Toei Animation",
+    0xad => "Toho",
+    0xaf => "Namco",
+    0xb0 => "Acclaim Entertainment",
+    0xb1 => "ASCII Corporation or Nexsoft",
+    0xb2 => "Bandai",
+    0xb4 => "Square Enix",
+    0xb6 => "HAL Laboratory",
+    0xb7 => "SNK",
+    0xb9 => "Pony Canyon",
+    0xba => "Culture Brain",
+    0xbb => "Sunsoft",
+    0xbd => "Sony Imagesoft",
+    0xbf => "Sammy Corporation",
+    0xc0 => "Taito",
+    0xc2 => "Kemco",
+    0xc3 => "Square",
+    0xc4 => "Tokuma Shoten",
+    0xc5 => "Data East",
+    0xc6 => "Tonkin House",
+    0xc8 => "Koei",
+    0xc9 => "UFL",
+    0xca => "Ultra Games",
+    0xcb => "VAP, Inc.",
+    0xcc => "Use Corporation",
+    0xcd => "Meldac",
+    0xce => "Pony Canyon",
+    0xcf => "Angel",
+    0xd0 => "Taito",
+    0xd1 => "SOFEL",
+    0xd2 => "Quest",
+    0xd3 => "Sigma Enterprises",
+    0xd4 => "ASK Kodansha Co.",
+    0xd6 => "Naxat Soft",
+    0xd7 => "Copya System",
+    0xd9 => "Banpresto",
+    0xda => "Tomy",
+    0xdb => "LJN",
+    0xdd => "Nippon Computer Systems",
+    0xde => "Human Ent.",
+    0xdf => "Altron",
+    0xe0 => "Jaleco",
+    0xe1 => "Towa Chiki",
+    0xe2 => "Yutaka",
+    0xe3 => "Varie",
+    0xe5 => "Epoch",
+    0xe7 => "Athena",
+    0xe8 => "Asmik Ace Entertainment",
+    0xe9 => "Natsume",
+    0xea => "King Records",
+    0xeb => "Atlus",
+    0xec => "Epic/Sony Records",
+    0xee => "IGS",
+    0xf0 => "A Wave",
+    0xf3 => "Extreme Entertainment",
+    0xff => "LJN",
+    _ => return None,
+  };
+
+  Some(name)
+}
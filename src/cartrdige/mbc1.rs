@@ -0,0 +1,119 @@
+use log::info;
+
+use crate::definitions::EXT_RAM_START;
+use super::{ROM_BANK_SIZE, RAM_BANK_SIZE, NINTENDO_LOGO, load_ram, Cartridge};
+
+// https://gbdev.io/pandocs/MBC1.html
+pub struct MBC1 {
+  rom: Vec<u8>,
+  ram: Vec<u8>,
+  rom_banks: usize,
+  ram_banks: usize,
+
+  ram_enabled: bool,
+  bank_low: u8,
+  bank_high: u8,
+  advanced_mode: bool,
+  multicart: bool,
+}
+
+impl MBC1 {
+  pub fn new(rom: Vec<u8>, ram: Vec<u8>) -> Self {
+    let rom_banks = rom.len() / ROM_BANK_SIZE;
+    let ram_banks = ram.len().div_ceil(RAM_BANK_SIZE);
+    let multicart = is_multicart(&rom);
+    if multicart { info!("[MBC1] Multicart detected."); }
+
+    MBC1 {
+      rom, ram, rom_banks, ram_banks,
+      ram_enabled: false,
+      bank_low: 1,
+      bank_high: 0,
+      advanced_mode: false,
+      multicart,
+    }
+  }
+
+  // MBC1M carts only wire 4 bits of the low bank register,
+  // so the high register selects one of the four 256 KiB games.
+  fn high_shift(&self) -> usize {
+    if self.multicart { 4 } else { 5 }
+  }
+
+  fn low_mask(&self) -> u8 {
+    if self.multicart { 0x0f } else { 0x1f }
+  }
+
+  fn zero_bank(&self) -> usize {
+    if !self.advanced_mode { return 0; }
+    ((self.bank_high as usize) << self.high_shift()) % self.rom_banks
+  }
+
+  fn switchable_bank(&self) -> usize {
+    let bank = ((self.bank_high as usize) << self.high_shift())
+      | (self.bank_low & self.low_mask()) as usize;
+    bank % self.rom_banks
+  }
+
+  fn ram_address(&self, addr: u16) -> Option<usize> {
+    if !self.ram_enabled || self.ram_banks == 0 { return None; }
+
+    let bank = if self.advanced_mode { self.bank_high as usize % self.ram_banks } else { 0 };
+    // carts with only 2 KiB of RAM still take the whole first bank.
+    Some((bank * RAM_BANK_SIZE + (addr - EXT_RAM_START) as usize) % self.ram.len())
+  }
+}
+
+impl Cartridge for MBC1 {
+  fn rom_read(&self, addr: u16) -> u8 {
+    let bank = match addr {
+      0x0000 ..= 0x3fff => self.zero_bank(),
+      _ => self.switchable_bank(),
+    };
+
+    self.rom[bank * ROM_BANK_SIZE + (addr as usize & 0x3fff)]
+  }
+
+  fn rom_write(&mut self, addr: u16, data: u8) {
+    match addr {
+      0x0000 ..= 0x1fff => self.ram_enabled = data & 0x0f == 0x0a,
+      0x2000 ..= 0x3fff => {
+        // The zero check is done on all 5 bits, so banks 0x20, 0x40 and 0x60
+        // can't be mapped in the switchable area, and become 0x21, 0x41 and 0x61.
+        self.bank_low = data & 0x1f;
+        if self.bank_low == 0 { self.bank_low = 1; }
+      }
+      0x4000 ..= 0x5fff => self.bank_high = data & 0b11,
+      _ => self.advanced_mode = data & 1 != 0,
+    }
+  }
+
+  fn ram_read(&self, addr: u16) -> u8 {
+    match self.ram_address(addr) {
+      Some(addr) => self.ram[addr],
+      None => 0xff,
+    }
+  }
+
+  fn ram_write(&mut self, addr: u16, data: u8) {
+    if let Some(addr) = self.ram_address(addr) {
+      self.ram[addr] = data;
+    }
+  }
+
+  fn save_data(&self) -> Vec<u8> {
+    self.ram.clone()
+  }
+
+  fn load_save_data(&mut self, data: &[u8]) -> Result<(), &'static str> {
+    load_ram(&mut self.ram, data)
+  }
+}
+
+// Multicarts are 1 MiB roms with another game header (and its logo) at bank 0x10.
+fn is_multicart(rom: &[u8]) -> bool {
+  if rom.len() != 64 * ROM_BANK_SIZE { return false; }
+
+  let logo_start = 0x10 * ROM_BANK_SIZE + 0x104;
+  rom[logo_start .. logo_start + NINTENDO_LOGO.len()] == NINTENDO_LOGO
+}
@@ -0,0 +1,71 @@
+use super::{ROM_BANK_SIZE, load_ram, Cartridge};
+
+const RAM_SIZE: usize = 512;
+
+// https://gbdev.io/pandocs/MBC2.html
+pub struct MBC2 {
+  rom: Vec<u8>,
+  ram: Vec<u8>,
+  rom_banks: usize,
+
+  ram_enabled: bool,
+  rom_bank: u8,
+}
+
+impl MBC2 {
+  pub fn new(rom: Vec<u8>) -> Self {
+    let rom_banks = rom.len() / ROM_BANK_SIZE;
+
+    MBC2 {
+      rom, rom_banks,
+      ram: vec![0; RAM_SIZE],
+      ram_enabled: false,
+      rom_bank: 1,
+    }
+  }
+}
+
+impl Cartridge for MBC2 {
+  fn rom_read(&self, addr: u16) -> u8 {
+    let bank = match addr {
+      0x0000 ..= 0x3fff => 0,
+      _ => self.rom_bank as usize % self.rom_banks,
+    };
+
+    self.rom[bank * ROM_BANK_SIZE + (addr as usize & 0x3fff)]
+  }
+
+  fn rom_write(&mut self, addr: u16, data: u8) {
+    if addr > 0x3fff { return; }
+
+    // bit 8 of the address selects which register gets written
+    if addr & 0x100 == 0 {
+      self.ram_enabled = data & 0x0f == 0x0a;
+    } else {
+      self.rom_bank = data & 0x0f;
+      if self.rom_bank == 0 { self.rom_bank = 1; }
+    }
+  }
+
+  // The built-in RAM is only 4 bits wide, and is echoed across the whole
+  // external RAM range. The upper nibble is left floating and reads as 1s.
+  fn ram_read(&self, addr: u16) -> u8 {
+    if !self.ram_enabled { return 0xff; }
+    self.ram[addr as usize % RAM_SIZE] | 0xf0
+  }
+
+  fn ram_write(&mut self, addr: u16, data: u8) {
+    if !self.ram_enabled { return; }
+    self.ram[addr as usize % RAM_SIZE] = data & 0x0f;
+  }
+
+  fn save_data(&self) -> Vec<u8> {
+    self.ram.clone()
+  }
+
+  fn load_save_data(&mut self, data: &[u8]) -> Result<(), &'static str> {
+    load_ram(&mut self.ram, data)?;
+    self.ram.iter_mut().for_each(|nibble| *nibble &= 0x0f);
+    Ok(())
+  }
+}
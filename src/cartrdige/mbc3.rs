@@ -0,0 +1,253 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+use log::info;
+
+use crate::definitions::{EXT_RAM_START, CLOCK_SPEED};
+use super::{ROM_BANK_SIZE, RAM_BANK_SIZE, load_ram, Cartridge};
+
+const DAY_HIGH: u8  = 1 << 0;
+const HALT: u8      = 1 << 6;
+const DAY_CARRY: u8 = 1 << 7;
+
+// Both BGB and VBA append the clock to the RAM dump: the live and latched
+// registers as 5 little endian u32 each, followed by a unix timestamp,
+// which is 64 bits wide in the 48 bytes layout and 32 bits in the 44 bytes one.
+const RTC_FOOTER_SIZE: usize = 48;
+const RTC_FOOTER_SIZE_OLD: usize = 44;
+
+// https://gbdev.io/pandocs/MBC3.html#the-clock-counter-registers
+// The clock is advanced by emulated cycles instead of wall time, so runs are deterministic.
+#[derive(Default, Clone, Copy)]
+pub struct RTC {
+  pub seconds: u8,
+  pub minutes: u8,
+  pub hours: u8,
+  pub day_low: u8,
+  pub day_high: u8,
+  pub cycles: usize,
+}
+
+impl RTC {
+  pub fn is_halted(&self) -> bool {
+    self.day_high & HALT != 0
+  }
+
+  pub fn read(&self, reg: u8) -> u8 {
+    match reg {
+      0x08 => self.seconds,
+      0x09 => self.minutes,
+      0x0a => self.hours,
+      0x0b => self.day_low,
+      _ => self.day_high,
+    }
+  }
+
+  pub fn write(&mut self, reg: u8, data: u8) {
+    match reg {
+      0x08 => {
+        self.seconds = data & 0x3f;
+        // writing the seconds resets the sub-second counter
+        self.cycles = 0;
+      }
+      0x09 => self.minutes = data & 0x3f,
+      0x0a => self.hours = data & 0x1f,
+      0x0b => self.day_low = data,
+      _ => self.day_high = data & (DAY_HIGH | HALT | DAY_CARRY),
+    }
+  }
+
+  fn to_footer(self) -> [u8; 20] {
+    let mut footer = [0; 20];
+    let regs = [self.seconds, self.minutes, self.hours, self.day_low, self.day_high];
+    for (chunk, reg) in footer.chunks_mut(4).zip(regs) {
+      chunk.copy_from_slice(&(reg as u32).to_le_bytes());
+    }
+    footer
+  }
+
+  fn from_footer(footer: &[u8]) -> Self {
+    let mut rtc = RTC::default();
+    for (reg, chunk) in (0x08 ..= 0x0c).zip(footer.chunks(4)) {
+      rtc.write(reg, chunk[0]);
+    }
+    rtc
+  }
+
+  pub fn tick(&mut self, cycles: usize) {
+    if self.is_halted() { return; }
+
+    self.cycles += cycles;
+    while self.cycles >= CLOCK_SPEED {
+      self.cycles -= CLOCK_SPEED;
+      self.inc_seconds();
+    }
+  }
+
+  // Counters set to an invalid value keep counting up to their bit width,
+  // wrapping to 0 without carrying into the next one.
+  fn inc_seconds(&mut self) {
+    self.seconds = (self.seconds + 1) & 0x3f;
+    if self.seconds == 60 {
+      self.seconds = 0;
+      self.inc_minutes();
+    }
+  }
+
+  fn inc_minutes(&mut self) {
+    self.minutes = (self.minutes + 1) & 0x3f;
+    if self.minutes == 60 {
+      self.minutes = 0;
+      self.inc_hours();
+    }
+  }
+
+  fn inc_hours(&mut self) {
+    self.hours = (self.hours + 1) & 0x1f;
+    if self.hours == 24 {
+      self.hours = 0;
+      self.inc_days();
+    }
+  }
+
+  fn inc_days(&mut self) {
+    let (day_low, overflow) = self.day_low.overflowing_add(1);
+    self.day_low = day_low;
+    if !overflow { return; }
+
+    if self.day_high & DAY_HIGH != 0 {
+      self.day_high &= !DAY_HIGH;
+      self.day_high |= DAY_CARRY;
+    } else {
+      self.day_high |= DAY_HIGH;
+    }
+  }
+}
+
+pub struct MBC3 {
+  rom: Vec<u8>,
+  ram: Vec<u8>,
+  rom_banks: usize,
+  ram_banks: usize,
+
+  ram_enabled: bool,
+  rom_bank: u8,
+  ram_bank: u8,
+  latch_armed: bool,
+
+  has_rtc: bool,
+  pub rtc: RTC,
+  pub latched_rtc: RTC,
+}
+
+impl MBC3 {
+  pub fn new(rom: Vec<u8>, ram: Vec<u8>, has_rtc: bool) -> Self {
+    let rom_banks = rom.len() / ROM_BANK_SIZE;
+    let ram_banks = ram.len().div_ceil(RAM_BANK_SIZE);
+
+    MBC3 {
+      rom, ram, rom_banks, ram_banks,
+      ram_enabled: false,
+      rom_bank: 1,
+      ram_bank: 0,
+      latch_armed: false,
+      has_rtc,
+      rtc: RTC::default(),
+      latched_rtc: RTC::default(),
+    }
+  }
+
+  fn ram_address(&self, addr: u16) -> Option<usize> {
+    if self.ram_banks == 0 { return None; }
+
+    let bank = self.ram_bank as usize % self.ram_banks;
+    Some((bank * RAM_BANK_SIZE + (addr - EXT_RAM_START) as usize) % self.ram.len())
+  }
+}
+
+impl Cartridge for MBC3 {
+  fn rom_read(&self, addr: u16) -> u8 {
+    let bank = match addr {
+      0x0000 ..= 0x3fff => 0,
+      _ => self.rom_bank as usize % self.rom_banks,
+    };
+
+    self.rom[bank * ROM_BANK_SIZE + (addr as usize & 0x3fff)]
+  }
+
+  fn rom_write(&mut self, addr: u16, data: u8) {
+    match addr {
+      0x0000 ..= 0x1fff => self.ram_enabled = data & 0x0f == 0x0a,
+      0x2000 ..= 0x3fff => {
+        self.rom_bank = data & 0x7f;
+        if self.rom_bank == 0 { self.rom_bank = 1; }
+      }
+      0x4000 ..= 0x5fff => self.ram_bank = data,
+      _ => {
+        // writing 0x00 and then 0x01 copies the clock in the latched registers
+        if self.latch_armed && data == 0x01 {
+          self.latched_rtc = self.rtc;
+          info!("[MBC3] RTC latched.");
+        }
+        self.latch_armed = data == 0x00;
+      }
+    }
+  }
+
+  fn ram_read(&self, addr: u16) -> u8 {
+    if !self.ram_enabled { return 0xff; }
+
+    match self.ram_bank {
+      0x00 ..= 0x03 => match self.ram_address(addr) {
+        Some(addr) => self.ram[addr],
+        None => 0xff,
+      }
+      0x08 ..= 0x0c => self.latched_rtc.read(self.ram_bank),
+      _ => 0xff,
+    }
+  }
+
+  fn ram_write(&mut self, addr: u16, data: u8) {
+    if !self.ram_enabled { return; }
+
+    match self.ram_bank {
+      0x00 ..= 0x03 => if let Some(addr) = self.ram_address(addr) {
+        self.ram[addr] = data;
+      }
+      0x08 ..= 0x0c => {
+        self.rtc.write(self.ram_bank, data);
+        self.latched_rtc.write(self.ram_bank, data);
+      }
+      _ => {}
+    }
+  }
+
+  fn tick(&mut self, cycles: usize) {
+    self.rtc.tick(cycles);
+  }
+
+  fn save_data(&self) -> Vec<u8> {
+    let mut data = self.ram.clone();
+    if !self.has_rtc { return data; }
+
+    data.extend(self.rtc.to_footer());
+    data.extend(self.latched_rtc.to_footer());
+
+    let timestamp = SystemTime::now()
+      .duration_since(UNIX_EPOCH)
+      .map(|time| time.as_secs())
+      .unwrap_or(0);
+    data.extend(timestamp.to_le_bytes());
+    data
+  }
+
+  // The timestamp is ignored, as the clock only advances with emulated cycles.
+  fn load_save_data(&mut self, data: &[u8]) -> Result<(), &'static str> {
+    load_ram(&mut self.ram, data)?;
+
+    let footer = &data[self.ram.len()..];
+    if self.has_rtc && (footer.len() == RTC_FOOTER_SIZE || footer.len() == RTC_FOOTER_SIZE_OLD) {
+      self.rtc = RTC::from_footer(&footer[0..20]);
+      self.latched_rtc = RTC::from_footer(&footer[20..40]);
+    }
+    Ok(())
+  }
+}
@@ -0,0 +1,96 @@
+use crate::definitions::EXT_RAM_START;
+use super::{ROM_BANK_SIZE, RAM_BANK_SIZE, load_ram, Cartridge};
+
+const RUMBLE_MOTOR: u8 = 1 << 3;
+
+// https://gbdev.io/pandocs/MBC5.html
+pub struct MBC5 {
+  rom: Vec<u8>,
+  ram: Vec<u8>,
+  rom_banks: usize,
+  ram_banks: usize,
+
+  ram_enabled: bool,
+  rom_bank: u16,
+  ram_bank: u8,
+  has_rumble: bool,
+  rumble: bool,
+}
+
+impl MBC5 {
+  pub fn new(rom: Vec<u8>, ram: Vec<u8>, has_rumble: bool) -> Self {
+    let rom_banks = rom.len() / ROM_BANK_SIZE;
+    let ram_banks = ram.len().div_ceil(RAM_BANK_SIZE);
+
+    MBC5 {
+      rom, ram, rom_banks, ram_banks,
+      ram_enabled: false,
+      rom_bank: 1,
+      ram_bank: 0,
+      has_rumble,
+      rumble: false,
+    }
+  }
+
+  fn ram_address(&self, addr: u16) -> Option<usize> {
+    if !self.ram_enabled || self.ram_banks == 0 { return None; }
+
+    let bank = self.ram_bank as usize % self.ram_banks;
+    Some((bank * RAM_BANK_SIZE + (addr - EXT_RAM_START) as usize) % self.ram.len())
+  }
+}
+
+impl Cartridge for MBC5 {
+  fn rom_read(&self, addr: u16) -> u8 {
+    // unlike the other MBCs, bank 0 can be mapped in the switchable area too
+    let bank = match addr {
+      0x0000 ..= 0x3fff => 0,
+      _ => self.rom_bank as usize % self.rom_banks,
+    };
+
+    self.rom[bank * ROM_BANK_SIZE + (addr as usize & 0x3fff)]
+  }
+
+  fn rom_write(&mut self, addr: u16, data: u8) {
+    match addr {
+      0x0000 ..= 0x1fff => self.ram_enabled = data == 0x0a,
+      0x2000 ..= 0x2fff => self.rom_bank = (self.rom_bank & 0x100) | data as u16,
+      0x3000 ..= 0x3fff => self.rom_bank = (self.rom_bank & 0xff) | ((data as u16 & 1) << 8),
+      0x4000 ..= 0x5fff => {
+        // on rumble carts bit 3 drives the motor instead of the RAM chip
+        if self.has_rumble {
+          self.rumble = data & RUMBLE_MOTOR != 0;
+          self.ram_bank = data & 0x07;
+        } else {
+          self.ram_bank = data & 0x0f;
+        }
+      }
+      _ => {}
+    }
+  }
+
+  fn ram_read(&self, addr: u16) -> u8 {
+    match self.ram_address(addr) {
+      Some(addr) => self.ram[addr],
+      None => 0xff,
+    }
+  }
+
+  fn ram_write(&mut self, addr: u16, data: u8) {
+    if let Some(addr) = self.ram_address(addr) {
+      self.ram[addr] = data;
+    }
+  }
+
+  fn save_data(&self) -> Vec<u8> {
+    self.ram.clone()
+  }
+
+  fn load_save_data(&mut self, data: &[u8]) -> Result<(), &'static str> {
+    load_ram(&mut self.ram, data)
+  }
+
+  fn rumble(&self) -> bool {
+    self.rumble
+  }
+}
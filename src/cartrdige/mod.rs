@@ -0,0 +1,94 @@
+use log::warn;
+
+use crate::definitions::{ROM_START, ROM_END, EXT_RAM_START, EXT_RAM_END};
+
+pub mod header;
+pub mod nombc;
+pub mod mbc1;
+pub mod mbc2;
+pub mod mbc3;
+pub mod mbc5;
+
+pub use header::{CartridgeData, HeaderError, CartType, RomSize, RamSize, Destination, Licensee, CgbSupport};
+use nombc::NoMBC;
+use mbc1::MBC1;
+use mbc2::MBC2;
+use mbc3::MBC3;
+use mbc5::MBC5;
+
+pub const ROM_BANK_SIZE: usize = 16 * 1024;
+pub const RAM_BANK_SIZE: usize = 8 * 1024;
+
+pub const NINTENDO_LOGO: [u8; 48] = [
+  0xce, 0xed, 0x66, 0x66, 0xcc, 0x0d, 0x00, 0x0b, 0x03, 0x73, 0x00, 0x83, 0x00, 0x0c, 0x00, 0x0d,
+  0x00, 0x08, 0x11, 0x1f, 0x88, 0x89, 0x00, 0x0e, 0xdc, 0xcc, 0x6e, 0xe6, 0xdd, 0xdd, 0xd9, 0x99,
+  0xbb, 0xbb, 0x67, 0x63, 0x6e, 0x0e, 0xec, 0xcc, 0xdd, 0xdc, 0x99, 0x9f, 0xbb, 0xb9, 0x33, 0x3e,
+];
+
+fn load_ram(ram: &mut [u8], data: &[u8]) -> Result<(), &'static str> {
+  if data.len() < ram.len() {
+    return Err("Save data is smaller than the cartridge RAM.");
+  }
+
+  ram.copy_from_slice(&data[..ram.len()]);
+  Ok(())
+}
+
+// Everything the bus needs from a cartridge. Each mapper lives in its own module,
+// and gets picked by new_cartridge() from the header's cartridge type.
+pub trait Cartridge {
+  fn rom_read(&self, addr: u16) -> u8;
+  fn rom_write(&mut self, addr: u16, data: u8);
+  fn ram_read(&self, addr: u16) -> u8;
+  fn ram_write(&mut self, addr: u16, data: u8);
+
+  fn save_data(&self) -> Vec<u8>;
+  fn load_save_data(&mut self, data: &[u8]) -> Result<(), &'static str>;
+
+  fn tick(&mut self, _cycles: usize) {}
+  fn rumble(&self) -> bool { false }
+
+  fn read(&self, addr: u16) -> u8 {
+    match addr {
+      ROM_START ..= ROM_END => self.rom_read(addr),
+      EXT_RAM_START ..= EXT_RAM_END => self.ram_read(addr),
+      _ => panic!("Address {addr:#06x} doesn't belong to the cartridge."),
+    }
+  }
+
+  fn write(&mut self, addr: u16, data: u8) {
+    match addr {
+      ROM_START ..= ROM_END => self.rom_write(addr, data),
+      EXT_RAM_START ..= EXT_RAM_END => self.ram_write(addr, data),
+      _ => panic!("Address {addr:#06x} doesn't belong to the cartridge."),
+    }
+  }
+}
+
+pub fn new_cartridge(mut rom: Vec<u8>, info: &CartridgeData) -> Box<dyn Cartridge> {
+  // the file might be smaller than what the header declares
+  let rom_size = info.rom_size.bytes().max(rom.len());
+  rom.resize(rom_size, 0);
+
+  let ram = vec![0; info.ram_size.bytes()];
+  let cart_type = info.cart_type;
+
+  match cart_type {
+    CartType::RomOnly | CartType::RomRam | CartType::RomRamBattery =>
+      Box::new(NoMBC::new(rom, ram)),
+    CartType::MBC1 | CartType::MBC1Ram | CartType::MBC1RamBattery =>
+      Box::new(MBC1::new(rom, ram)),
+    CartType::MBC2 | CartType::MBC2Battery =>
+      Box::new(MBC2::new(rom)),
+    CartType::MBC3TimerBattery | CartType::MBC3TimerRamBattery |
+    CartType::MBC3 | CartType::MBC3Ram | CartType::MBC3RamBattery =>
+      Box::new(MBC3::new(rom, ram, cart_type.has_timer())),
+    CartType::MBC5 | CartType::MBC5Ram | CartType::MBC5RamBattery |
+    CartType::MBC5Rumble | CartType::MBC5RumbleRam | CartType::MBC5RumbleRamBattery =>
+      Box::new(MBC5::new(rom, ram, cart_type.has_rumble())),
+    _ => {
+      warn!("Cartridge type {cart_type} not supported, falling back to no MBC.");
+      Box::new(NoMBC::new(rom, ram))
+    }
+  }
+}
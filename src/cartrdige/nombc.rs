@@ -0,0 +1,44 @@
+use log::info;
+
+use crate::definitions::EXT_RAM_START;
+use super::{load_ram, Cartridge};
+
+pub struct NoMBC {
+  rom: Vec<u8>,
+  ram: Vec<u8>,
+}
+
+impl NoMBC {
+  pub fn new(rom: Vec<u8>, ram: Vec<u8>) -> Self {
+    NoMBC { rom, ram }
+  }
+}
+
+impl Cartridge for NoMBC {
+  fn rom_read(&self, addr: u16) -> u8 {
+    self.rom[addr as usize]
+  }
+
+  fn rom_write(&mut self, addr: u16, _data: u8) {
+    info!("[NoMBC] Trying to write ROM memory at {addr:#04x}.");
+  }
+
+  fn ram_read(&self, addr: u16) -> u8 {
+    if self.ram.is_empty() { return 0xff; }
+    self.ram[(addr - EXT_RAM_START) as usize % self.ram.len()]
+  }
+
+  fn ram_write(&mut self, addr: u16, data: u8) {
+    if self.ram.is_empty() { return; }
+    let len = self.ram.len();
+    self.ram[(addr - EXT_RAM_START) as usize % len] = data;
+  }
+
+  fn save_data(&self) -> Vec<u8> {
+    self.ram.clone()
+  }
+
+  fn load_save_data(&mut self, data: &[u8]) -> Result<(), &'static str> {
+    load_ram(&mut self.ram, data)
+  }
+}
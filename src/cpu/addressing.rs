@@ -8,7 +8,7 @@ pub struct Opcode {
   pub bytes: u8,
   pub cycles: (usize, usize),
   pub immediate: bool,
-  pub operands: Vec<Operand>,
+  pub operands: &'static [Operand],
 }
 
 
@@ -62,7 +62,7 @@ pub enum ConditionOperand { Z, NZ, CY, NC }
 pub enum LiteralOperand { n8, n16, a8, a16, e8 }
 
 impl CPU {
-  pub(super) fn get_from_source(&self, src: &Operand) -> u16 {
+  pub(super) fn get_from_source(&mut self, src: &Operand) -> u16 {
     let data_to_get = match src.kind {
       OperandType::Register(reg) => {
         match reg {
@@ -92,18 +92,11 @@ impl CPU {
         }
       },
 
+      // the operand was already fetched with the opcode
       OperandType::Literal(lit) => {
-        // when computing the instruction, the pc is pointing to the next instruction.
-        // we have to decrease it to read the operand.
         match lit {
-          LiteralOperand::n8 | LiteralOperand::e8 => 
-            self.mem_read(self.pc.wrapping_sub(1)) as u16,
-
-          LiteralOperand::a8 => 
-            0xFF00 | self.mem_read(self.pc.wrapping_sub(1)) as u16,
-
-          LiteralOperand::n16 | LiteralOperand::a16 => 
-            self.mem_read_16(self.pc.wrapping_sub(2)),
+          LiteralOperand::a8 => 0xFF00 | self.immediate,
+          _ => self.immediate,
         }
       },
 
@@ -112,7 +105,7 @@ impl CPU {
     };
 
     if src.immediate { data_to_get }
-    else { self.mem_read(data_to_get) as u16 }
+    else { self.read_cycle(data_to_get) as u16 }
   }
 
   pub(super) fn set_to_destination(&mut self, dst: &Operand, data: u8) {
@@ -137,13 +130,8 @@ impl CPU {
       },
 
       OperandType::Literal(lit) => {
-        // when computing the instruction, the pc is pointing to the next instruction.
-        // we have to decrease it to read the operand.
         match lit {
-          LiteralOperand::a16 => {
-            let addr = self.mem_read_16(self.pc.wrapping_sub(2));
-            self.mem_write(addr, data as u8);
-          }
+          LiteralOperand::a16 => self.write_cycle(self.immediate, data),
           _ => panic!("Impossible to address 8bit literal value.")
         }
       },
@@ -165,12 +153,9 @@ impl CPU {
       },
 
       OperandType::Literal(lit) => {
-        // when computing the instruction, the pc is pointing to the next instruction.
-        // we have to decrease it to read the operand.
         match lit {
-          LiteralOperand::a8 => 0xFF00 | self.mem_read(self.pc.wrapping_sub(1)) as u16,
-          LiteralOperand::a16 => self.mem_read_16(self.pc.wrapping_sub(2)),
-          
+          LiteralOperand::a8 => 0xFF00 | self.immediate,
+          LiteralOperand::a16 => self.immediate,
           _ => panic!("Impossible to address 8bit literal value.")
         } 
       },
@@ -178,7 +163,7 @@ impl CPU {
       _ => panic!("Impossible destination to set.")
     };
 
-    self.mem_write(addr, data);
+    self.write_cycle(addr, data);
   }
 
   pub(super) fn set_to_destination_16(&mut self, dst: &Operand, data: u16) {
@@ -194,13 +179,13 @@ impl CPU {
         }
       },
 
+      // LD (a16), SP writes the low byte first
       OperandType::Literal(lit) => {
-        // when computing the instruction, the pc is pointing to the next instruction.
-        // we have to decrease it to read the operand.
         match lit {
           LiteralOperand::a16 => {
-            let addr = self.mem_read_16(self.pc.wrapping_sub(2));
-            self.mem_write_16(addr, data);
+            let [low, high] = data.to_le_bytes();
+            self.write_cycle(self.immediate, low);
+            self.write_cycle(self.immediate.wrapping_add(1), high);
           },
           _ => panic!("Impossible to address 8bit literal value.")
         }
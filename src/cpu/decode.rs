@@ -1,26 +1,25 @@
 use super::{addressing::Opcode, CPU};
 
 impl CPU {
+  // Always inlined, so the match folds away for the constant opcodes of the dispatch table.
+  #[inline(always)]
   pub fn decode(&mut self, opcode: &Opcode) {
-    if opcode.prefixed {
-      self.cb_decode(opcode);
-      return;
-    }
     let operands = &opcode.operands;
 
     match opcode.code {
       0x00 => (),
-      0x10 => panic!("STOP reached"), // STOP
+      0x10 => self.stop(),
       0x76 => self.halt(),
 
       0x01 | 0x02 | 0x06 | 0x08 | 0x0A |
       0x0E | 0x11 | 0x12 | 0x16 | 0x1A |
       0x1E | 0x21 | 0x26 | 0x2E | 0x31 |
-      0x36 | 0x3E | 0xEA | 0xFA | 0xF9 | 
+      0x36 | 0x3E | 0xEA | 0xFA |
       0xE0 | 0xF0 | 0x40 ..= 0x7F
       => self.ld(&operands[0], &operands[1]),
 
       0xF8 => self.ld_sp_sign(&operands[2]),
+      0xF9 => self.ld_sp_hl(),
       0x22 | 0x2A => self.ldi(&operands[0], &operands[1]),
       0x32 | 0x3A => self.ldd(&operands[0], &operands[1]),
       0xE2 => self.ld_a_to_io_in_c_reg(),
@@ -80,6 +79,8 @@ impl CPU {
       _ => unimplemented!("Unimplemented instruction {:04x}.", opcode.code),
     }
   }
+
+  #[inline(always)]
   pub fn cb_decode(&mut self, opcode: &Opcode) {
     let operands = &opcode.operands;
 
@@ -0,0 +1,73 @@
+use super::CPU;
+use super::optable::{OPTABLE, CB_OPTABLE};
+#[cfg(feature = "hashmap_dispatch")]
+use {std::{collections::HashMap, sync::OnceLock}, super::addressing::Opcode};
+
+// An entry per opcode, called with the address the opcode was fetched from.
+pub type Handler = fn(&mut CPU, u16);
+
+// Lists the 256 instances of a handler generic over the opcode.
+macro_rules! dispatch_table {
+  ($handler:ident) => {
+    dispatch_table!(@row $handler []
+      0x00 0x10 0x20 0x30 0x40 0x50 0x60 0x70 0x80 0x90 0xa0 0xb0 0xc0 0xd0 0xe0 0xf0)
+  };
+  (@row $handler:ident [$($done:expr),*] $high:literal $($rest:literal)*) => {
+    dispatch_table!(@row $handler [$($done,)*
+      $handler::<{$high + 0x0}>, $handler::<{$high + 0x1}>, $handler::<{$high + 0x2}>, $handler::<{$high + 0x3}>,
+      $handler::<{$high + 0x4}>, $handler::<{$high + 0x5}>, $handler::<{$high + 0x6}>, $handler::<{$high + 0x7}>,
+      $handler::<{$high + 0x8}>, $handler::<{$high + 0x9}>, $handler::<{$high + 0xa}>, $handler::<{$high + 0xb}>,
+      $handler::<{$high + 0xc}>, $handler::<{$high + 0xd}>, $handler::<{$high + 0xe}>, $handler::<{$high + 0xf}>
+    ] $($rest)*)
+  };
+  (@row $handler:ident [$($done:expr),*]) => { [$($done),*] };
+}
+
+pub const DISPATCH: [Handler; 256] = dispatch_table!(execute);
+pub const CB_DISPATCH: [Handler; 256] = dispatch_table!(cb_execute);
+
+// The opcode is a constant in every instance, so are its operands.
+fn execute<const CODE: u8>(cpu: &mut CPU, pc: u16) {
+  let opcode = const { &OPTABLE[CODE as usize] };
+  cpu.log_op(pc, opcode);
+
+  // the operands always come right after the opcode
+  let mut immediate = [0; 2];
+  for byte in immediate.iter_mut().take(opcode.bytes as usize - 1) {
+    *byte = cpu.fetch();
+  }
+  cpu.immediate = u16::from_le_bytes(immediate);
+
+  cpu.decode(opcode);
+}
+
+fn cb_execute<const CODE: u8>(cpu: &mut CPU, pc: u16) {
+  let opcode = const { &CB_OPTABLE[CODE as usize] };
+  cpu.log_op(pc, opcode);
+  cpu.cb_decode(opcode);
+}
+
+// The HashMap lookup the tables replaced, only kept to compare the two with
+// `cargo bench --bench frames --features hashmap_dispatch`.
+#[cfg(feature = "hashmap_dispatch")]
+pub fn hashmap_execute(cpu: &mut CPU, code: u16, pc: u16) {
+  static OPCODES: OnceLock<HashMap<u16, Opcode>> = OnceLock::new();
+  let opcodes = OPCODES.get_or_init(|| {
+    let prefixed = CB_OPTABLE.iter().map(|opcode| (0xCB00 | opcode.code as u16, opcode.clone()));
+    OPTABLE.iter().map(|opcode| (opcode.code as u16, opcode.clone())).chain(prefixed).collect()
+  });
+
+  let opcode = opcodes.get(&code).unwrap();
+  cpu.log_op(pc, opcode);
+  if opcode.prefixed {
+    cpu.cb_decode(opcode);
+    return;
+  }
+
+  let mut immediate = [0; 2];
+  for byte in immediate.iter_mut().take(opcode.bytes as usize - 1) {
+    *byte = cpu.fetch();
+  }
+  cpu.immediate = u16::from_le_bytes(immediate);
+  cpu.decode(opcode);
+}
@@ -83,12 +83,12 @@ impl CPU {
 
   pub fn ld_io_in_c_reg_to_a(&mut self) {
     let addr = 0xFF00 + self.c as u16;
-    self.a = self.mem_read(addr);
+    self.a = self.read_cycle(addr);
   }
 
   pub fn ld_a_to_io_in_c_reg(&mut self) {
     let addr = 0xFF00 + self.c as u16;
-    self.mem_write(addr, self.a);
+    self.write_cycle(addr, self.a);
   }
 
   pub fn ldi(&mut self, dst: &Operand, src: &Operand) {
@@ -103,8 +103,10 @@ impl CPU {
     self.set_hl(hl.wrapping_sub(1));
   }
 
+  // SP is decremented in an internal cycle before the writes
   pub fn push(&mut self, src: &Operand) {
     let data = self.get_from_source(src);
+    self.tick(4);
     self.stack_push(data);
   }
 
@@ -196,6 +198,7 @@ impl CPU {
     let result = data.wrapping_add(1);
 
     if dst.is_value_16() {
+      self.tick(4);
       self.set_to_destination_16(dst, result);
     } else {
       self.f.remove(Flags::SUB);
@@ -211,6 +214,7 @@ impl CPU {
     let result = data.wrapping_sub(1);
 
     if dst.is_value_16() {
+      self.tick(4);
       self.set_to_destination_16(dst, result);
     } else {
       self.f.insert(Flags::SUB);
@@ -225,6 +229,7 @@ impl CPU {
     let data = self.get_from_source(src);
     let hl = self.get_hl();
     let result = hl.wrapping_add(data);
+    self.tick(4);
     self.set_hl(result);
 
     self.f.remove(Flags::SUB);
@@ -252,6 +257,7 @@ impl CPU {
       self.update_carry(self.sp as u8, data as u8, 0);
     }
 
+    self.tick(8);
     self.sp = result;
   }
 
@@ -274,9 +280,15 @@ impl CPU {
       self.update_carry(self.sp as u8, data as u8, 0);
     }
 
+    self.tick(4);
     self.set_hl(result);
   }
 
+  pub fn ld_sp_hl(&mut self) {
+    self.tick(4);
+    self.sp = self.get_hl();
+  }
+
   pub fn daa(&mut self) {
     let a = self.a;
 
@@ -422,6 +434,8 @@ impl CPU {
 
   pub fn jp(&mut self, dst: &Operand) {
     let addr = self.get_from_source(dst);
+    // JP HL doesn't wait for the new PC
+    if !matches!(dst.kind, OperandType::Register(_)) { self.tick(4); }
     self.pc = addr;
   }
 
@@ -435,6 +449,7 @@ impl CPU {
 
   pub fn jr(&mut self, dst: &Operand) {
     let addr = self.get_from_source(dst) as i8;
+    self.tick(4);
     self.pc = self.pc
       .wrapping_add_signed(addr as i16);
   }
@@ -448,8 +463,9 @@ impl CPU {
 
   pub fn call(&mut self, dst: &Operand) {
     // The program counter points to the next instruction before the current instruction is evaluated. 
-    self.stack_push(self.pc);
     let addr = self.get_from_source(dst);
+    self.tick(4);
+    self.stack_push(self.pc);
     self.pc = addr;
   }
 
@@ -462,10 +478,13 @@ impl CPU {
 
   pub fn ret(&mut self) {
     self.pc = self.stack_pop();
+    self.tick(4);
   }
 
+  // checking the condition takes an M-cycle
   pub fn retc(&mut self, cond: &Operand) {
     let cond = self.get_from_source(cond);
+    self.tick(4);
     if cond != 0 {
       self.ret();
     }
@@ -474,6 +493,7 @@ impl CPU {
   pub fn rst(&mut self, dst: &Operand) {
     let addr = self.get_from_source(dst);
     // The program counter points to the next instruction before the current instruction is evaluated. 
+    self.tick(4);
     self.stack_push(self.pc);
     self.pc = addr;
   }
@@ -484,9 +504,12 @@ impl CPU {
     info!("[EI] IF Flag: {:?}, IE Flag: {:?}", self.get_if(), self.get_ie());
     self.ime_to_set = true;
   }
+  // unlike EI, RETI enables the interrupts right away, the next one is
+  // dispatched before the instruction it returns to
   pub fn reti(&mut self) {
-    self.ime = true;
     self.ret();
+    self.ime = true;
+    self.ime_to_set = false;
   }
 
   pub fn bit(&mut self, bit: &Operand, src: &Operand) {
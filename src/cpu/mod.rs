@@ -3,13 +3,15 @@
 use std::{cell::RefCell, rc::Rc};
 
 use crate::{definitions::*, bus::{BUS, InterruptRegister}};
-use log::{debug, info, trace};
-use optable::OPTABLE;
+use log::{debug, info, trace, log_enabled, Level};
+#[cfg(not(feature = "hashmap_dispatch"))]
+use dispatch::{DISPATCH, CB_DISPATCH};
 use addressing::Opcode;
 
 mod addressing;
 mod instructions;
 mod decode;
+mod dispatch;
 pub mod optable;
 
 bitflags::bitflags! {
@@ -37,7 +39,15 @@ pub struct CPU {
 
   pub ime: bool,
   pub ime_to_set: bool,
+  // true during the instruction right after EI
+  pub ime_just_set: bool,
   pub halted: bool,
+  // STOP, only a joypad line going low wakes the CPU
+  pub stopped: bool,
+  // the byte after HALT is read twice
+  pub halt_bug: bool,
+  // the n8, e8, a8, n16 or a16 operand of the current instruction
+  pub immediate: u16,
 
   pub sp: u16,
   pub pc: u16,
@@ -60,7 +70,11 @@ impl CPU {
       pc: PC_INIT,
       ime: false,
       ime_to_set: false,
+      ime_just_set: false,
       halted: false,
+      stopped: false,
+      halt_bug: false,
+      immediate: 0,
       memory,
     }
   }
@@ -90,6 +104,25 @@ impl CPU {
     self.memory.borrow_mut()
     .tick(cycles);
   }
+
+  // Every access made by an instruction takes an M-cycle, the rest of the
+  // hardware advances before the memory is read or written.
+  pub fn read_cycle(&mut self, addr: u16) -> u8 {
+    self.tick(4);
+    self.mem_read(addr)
+  }
+
+  pub fn write_cycle(&mut self, addr: u16, data: u8) {
+    self.tick(4);
+    self.mem_write(addr, data);
+  }
+
+  pub fn fetch(&mut self) -> u8 {
+    let data = self.read_cycle(self.pc);
+    if self.halt_bug { self.halt_bug = false; }
+    else { self.pc = self.pc.wrapping_add(1); }
+    data
+  }
   
   pub fn mem_read_16(&self, addr: u16) -> u16 {
     let low = self.mem_read(addr);
@@ -120,25 +153,49 @@ impl CPU {
 
 // Important Stuff
 impl CPU {
+  // the high byte is pushed first
   pub fn stack_push(&mut self, data: u16) {
-    self.mem_write_16(self.sp.wrapping_sub(2), data);
-    self.sp = self.sp.wrapping_sub(2);
+    let [high, low] = data.to_be_bytes();
+    self.sp = self.sp.wrapping_sub(1);
+    self.write_cycle(self.sp, high);
+    self.sp = self.sp.wrapping_sub(1);
+    self.write_cycle(self.sp, low);
   }
 
   pub fn stack_pop(&mut self) -> u16 {
-    let value = self.mem_read_16(self.sp);
-    self.sp = self.sp.wrapping_add(2);
-    value
+    let low = self.read_cycle(self.sp);
+    self.sp = self.sp.wrapping_add(1);
+    let high = self.read_cycle(self.sp);
+    self.sp = self.sp.wrapping_add(1);
+    u16::from_le_bytes([low, high])
   }
 
+  // https://gbdev.io/pandocs/halt.html#halt-bug
   pub fn halt(&mut self) {
     let if_reg = self.get_if();
     let ie_reg = self.get_ie();
 
     if if_reg.bits() & ie_reg.bits() == 0 {
       self.halted = true;
-      self.tick(4);
+    } else if self.ime_just_set {
+      // right after EI, the interrupt returns to the HALT, which runs again
+      self.pc = self.pc.wrapping_sub(1);
+    } else if !self.ime {
+      self.halt_bug = true;
     }
+    // otherwise the interrupt was requested during the fetch, it's dispatched
+    // before the next instruction without halting
+  }
+
+  pub fn stop(&mut self) {
+    self.mem_write(0xff04, 0);
+    if self.memory.borrow_mut().switch_speed() { return; }
+    self.stopped = true;
+  }
+
+  // The selected buttons are the low nibble of P1, pulled low when pressed.
+  fn joypad_pressed(&self) -> bool {
+    self.mem_read(0xff00) & 0x0f != 0x0f
   }
 
   pub fn interrupts_handle(&mut self) {
@@ -152,10 +209,12 @@ impl CPU {
 
     // The CPU wakes up as soon as an interrupt is pending, that is,
     // when the bitwise AND of IE and IF is non-zero.
+    let halted = self.halted;
     self.halted = false;
 
-    // TODO: here the halt bug happens
     if !self.ime { return; }
+    // leaving HALT takes an extra M-cycle before the dispatch
+    if halted { self.tick(4); }
 
     info!("[InterruptsHandler] Checking for interrupts...");
     for (_, interrupt) in if_reg.iter_names() {
@@ -174,7 +233,6 @@ impl CPU {
   pub fn interrupt_call(&mut self, int: InterruptRegister) {
     self.tick(2 * 4);
     self.stack_push(self.pc);
-    self.tick(2 * 4);
 
     info!("[InterruptCall] PC pushed. Redirecting to interrupt vector...");
     match int {
@@ -213,8 +271,19 @@ impl CPU {
       return Err("Blargg test done");
     }
 
+    if self.stopped {
+      if !self.joypad_pressed() {
+        self.memory.borrow_mut().idle(4);
+        return Ok(());
+      }
+      self.stopped = false;
+    }
+
     self.interrupts_handle();
 
+    // EI takes effect after the next instruction, so it's applied after the
+    // interrupts check and before that instruction runs
+    self.ime_just_set = self.ime_to_set;
     if self.ime_to_set {
       info!("[EI] IME Enabled - IF Flag: {:?}, IE Flag: {:?}", self.get_if(), self.get_ie());
       self.ime_to_set = false;
@@ -227,34 +296,21 @@ impl CPU {
       return Ok(());
     }
 
-    let code = self.mem_read(self.pc);
-    let opcode = if code == 0xCB {
-      let code = 0xCB00 | self
-        .mem_read(self.pc.wrapping_add(1))
-        as u16;
-      OPTABLE.get(&code).unwrap()
-    } else {
-      OPTABLE.get(&(code as u16)).unwrap()
-    };
-
-    self.log_op(opcode);
-
-    self.pc = self.pc.wrapping_add(opcode.bytes as u16);
-    let pc_before_jpc = self.pc;
-    
-    self.decode(opcode);
-
-    if opcode.cycles.1 != 0 && self.pc == pc_before_jpc {
-      self.tick(opcode.cycles.1);
+    let pc = self.pc;
+    let code = self.fetch();
+    #[cfg(feature = "hashmap_dispatch")]
+    {
+      let code = if code == 0xCB { 0xCB00 | self.fetch() as u16 } else { code as u16 };
+      dispatch::hashmap_execute(self, code, pc);
+    }
+    #[cfg(not(feature = "hashmap_dispatch"))]
+    if code == 0xCB {
+      let code = self.fetch();
+      CB_DISPATCH[code as usize](self, pc);
     } else {
-      self.tick(opcode.cycles.0);
+      DISPATCH[code as usize](self, pc);
     }
 
-    if self.mem_read(0xff02) == 0x81 {
-      eprint!("{}", self.mem_read(0xff01) as char);
-      self.mem_write(0xff02, 0);
-    }
-    
     Ok(())
   }
 
@@ -275,9 +331,11 @@ impl CPU {
     )
   }
 
-  pub fn log_op(&self, opcode: &Opcode) {
-    let second = self.mem_read(self.pc.wrapping_add(1)); 
-    let third =  self.mem_read(self.pc.wrapping_add(2));
-    debug!("[Running] {:#06x}: {},\t({:#04x}, {:#04x}, {:#04x})", self.pc, opcode.name, opcode.code, second, third);
+  pub fn log_op(&self, pc: u16, opcode: &Opcode) {
+    // reading the operands back is too slow to do for every instruction
+    if !log_enabled!(Level::Debug) { return; }
+    let second = self.mem_read(pc.wrapping_add(1));
+    let third =  self.mem_read(pc.wrapping_add(2));
+    debug!("[Running] {:#06x}: {},\t({:#04x}, {:#04x}, {:#04x})", pc, opcode.name, opcode.code, second, third);
   }
 }
\ No newline at end of file
@@ -48,12 +48,15 @@ pub const L_INIT: u8 = 0x4d;
 pub const SP_INIT: u16 = 0xfffe;
 pub const PC_INIT: u16 = 0x0100;
 pub const DIV_INIT: u16 = 0xabcc;
+pub const LCDC_INIT: u8 = 0x91;
+pub const STAT_INIT: u8 = 0x85;
+pub const BGP_INIT: u8 = 0xfc;
 
 pub const LCD_WIDTH:  usize = 160;
 pub const LCD_HEIGHT: usize = 144;
 pub const VRAM_BLOCK_0: usize = 0x8000;
 pub const VRAM_BLOCK_1: usize = 0x8800;
-pub const VRAM_BLOCK_2: usize = 0x900;
+pub const VRAM_BLOCK_2: usize = 0x9000;
 pub const VRAM_TILE_MAP_0: usize = 0x9800;
 pub const VRAM_TILE_MAP_1: usize = 0x9c00;
 
@@ -1,33 +1,35 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::{Ref, RefCell}, rc::Rc, fs::File, io::{self, BufWriter}, path::Path};
 
 use cpu::CPU;
-use bus::BUS;
-use ppu::PPU;
+use bus::{BUS, joypad::Button, serial::{SerialDevice, LinkedDevice}};
+use bus::apu::{SAMPLE_RATE, resampler::Resampler, wav::write_wav};
+use cartrdige::{CartridgeData, HeaderError, new_cartridge};
+use definitions::{CYCLES_PER_FRAME, LCD_WIDTH, LCD_HEIGHT};
 
 pub mod cpu;
 pub mod ppu;
 pub mod bus;
 pub mod cartrdige;
 pub mod definitions;
+pub mod link;
+pub mod printer;
+
+pub const WAV_SAMPLE_RATE: usize = 44100;
 
 pub struct Emulator {
   pub cpu: CPU,
-  pub ppu: PPU,
   pub memory: Rc<RefCell<BUS>>,
-  
-  // TODO
-  // pub cartridge: CartridgeData,
+  pub cartridge: CartridgeData,
 }
 
 impl Emulator {
-  pub fn new(rom: Vec<u8>) -> Emulator {
-    // let cartridge = CartridgeData::new(&rom);
-    let memory = Rc::new(RefCell::new(BUS::new(rom)));
+  pub fn new(rom: Vec<u8>) -> Result<Emulator, HeaderError> {
+    let cartridge = CartridgeData::new(&rom)?;
+    let memory = Rc::new(RefCell::new(BUS::new(new_cartridge(rom, &cartridge))));
     
     let cpu = CPU::new(Rc::clone(&memory));
-    let ppu = PPU::new(Rc::clone(&memory));
 
-    Emulator { cpu, ppu, memory }
+    Ok(Emulator { cpu, memory, cartridge })
   }
 
   // to delete later
@@ -35,10 +37,110 @@ impl Emulator {
     self.cpu.run();
   }
 
-  pub fn step(&mut self) -> Result<(), &str> {
-    let res = self.cpu.step();
-    for _ in 0..4 { self.ppu.step() }
+  pub fn has_battery(&self) -> bool {
+    self.cartridge.has_battery()
+  }
+
+  // Battery backed RAM, followed by the RTC footer for MBC3 carts with a timer.
+  // Returns None when the cartridge doesn't have a battery.
+  pub fn save_data(&self) -> Option<Vec<u8>> {
+    if !self.has_battery() { return None; }
+    Some(self.memory.borrow().cartridge.save_data())
+  }
+
+  pub fn load_save_data(&mut self, data: &[u8]) -> Result<(), &'static str> {
+    if !self.has_battery() {
+      return Err("Cartridge doesn't have a battery.");
+    }
+    self.memory.borrow_mut().cartridge.load_save_data(data)
+  }
+
+  pub fn rumble(&self) -> bool {
+    self.memory.borrow().cartridge.rumble()
+  }
+
+  // Every cycle consumed by the CPU steps the rest of the hardware through the bus.
+  pub fn step(&mut self) -> Result<(), &'static str> {
+    self.cpu.step()
+  }
+
+  // Stops early when the CPU does, as the cycles wouldn't advance anymore.
+  pub fn step_frame(&mut self) -> Result<(), &'static str> {
+    let target = self.memory.borrow().cycles + CYCLES_PER_FRAME;
+    while self.memory.borrow().cycles < target {
+      self.cpu.step()?;
+    }
+    Ok(())
+  }
+
+  pub fn press(&mut self, button: Button) {
+    self.memory.borrow_mut().set_button(button, true);
+  }
+
+  pub fn release(&mut self, button: Button) {
+    self.memory.borrow_mut().set_button(button, false);
+  }
+
+  pub fn connect_serial(&mut self, device: Box<dyn SerialDevice>) {
+    self.memory.borrow_mut().serial.device = device;
+  }
+
+  // Plugs a link cable between the two emulators. They must be stepped
+  // alternately, so each one sees the transfers the other clocks.
+  pub fn link(&mut self, other: &mut Emulator) {
+    self.connect_serial(Box::new(LinkedDevice::new(&other.memory)));
+    other.connect_serial(Box::new(LinkedDevice::new(&self.memory)));
+  }
+
+  // Stereo samples produced since the last call, at apu::SAMPLE_RATE.
+  pub fn take_audio_samples(&mut self) -> Vec<(f32, f32)> {
+    std::mem::take(&mut self.memory.borrow_mut().apu.samples)
+  }
+
+  // Bit n set mutes channel n + 1, e.g. 0b1110 to only hear the first square channel.
+  pub fn set_audio_mute_mask(&mut self, mask: u8) {
+    self.memory.borrow_mut().apu.mute_mask = mask & 0x0f;
+  }
+
+  pub fn audio_mute_mask(&self) -> u8 {
+    self.memory.borrow().apu.mute_mask
+  }
+
+  // Runs the emulator for the given number of frames, and writes the audio output
+  // to `path` as a 44.1 kHz 16 bit stereo WAV file. With `per_channel`, every channel
+  // is also written on its own next to it, as name.ch1.wav to name.ch4.wav.
+  pub fn record_wav(&mut self, path: &Path, frames: usize, per_channel: bool) -> io::Result<()> {
+    self.take_audio_samples();
+    self.memory.borrow_mut().apu.channel_samples = per_channel.then(Vec::new);
+
+    let mut resampler = Resampler::new(SAMPLE_RATE, WAV_SAMPLE_RATE);
+    let mut channel_resamplers: [Resampler; 4] = std::array::from_fn(|_| Resampler::new(SAMPLE_RATE, WAV_SAMPLE_RATE));
+    let mut mixed = Vec::new();
+    let mut channels: [Vec<(f32, f32)>; 4] = Default::default();
+
+    for _ in 0..frames {
+      self.step_frame().map_err(io::Error::other)?;
+      resampler.process(&self.take_audio_samples(), &mut mixed);
+
+      let channel_samples = self.memory.borrow_mut().apu.channel_samples.as_mut().map(std::mem::take);
+      for (i, resampler) in channel_resamplers.iter_mut().enumerate() {
+        let samples: Vec<_> = channel_samples.iter().flatten().map(|sample| sample[i]).collect();
+        resampler.process(&samples, &mut channels[i]);
+      }
+    }
+    self.memory.borrow_mut().apu.channel_samples = None;
+
+    write_wav(BufWriter::new(File::create(path)?), WAV_SAMPLE_RATE as u32, &mixed)?;
+    if per_channel {
+      for (i, samples) in channels.iter().enumerate() {
+        let path = path.with_extension(format!("ch{}.wav", i + 1));
+        write_wav(BufWriter::new(File::create(path)?), WAV_SAMPLE_RATE as u32, samples)?;
+      }
+    }
+    Ok(())
+  }
 
-    res
+  pub fn framebuffer(&self) -> Ref<'_, [u8; LCD_WIDTH * LCD_HEIGHT]> {
+    Ref::map(self.memory.borrow(), |bus| &bus.ppu.framebuffer)
   }
 }
\ No newline at end of file
@@ -0,0 +1,159 @@
+use std::{cell::RefCell, io::{self, Read, Write}, net::{TcpListener, TcpStream, ToSocketAddrs}, rc::Rc};
+
+use crate::{Emulator, bus::serial::SerialDevice, definitions::CYCLES_PER_FRAME};
+
+// T-cycles each side runs before waiting for the other one. A byte transfer
+// takes 4096 cycles, so the slave gets it at most one transfer late.
+const SYNC_CYCLES: usize = 4096;
+
+// Every message is a kind followed by a data byte.
+const SYNC: u8 = 0;
+const TRANSFER: u8 = 1;
+const REPLY: u8 = 2;
+
+struct Connection {
+  stream: TcpStream,
+  // syncs received from the peer while waiting for something else
+  peer_syncs: usize,
+}
+
+impl Connection {
+  fn send(&mut self, kind: u8, data: u8) -> io::Result<()> {
+    self.stream.write_all(&[kind, data])
+  }
+
+  fn recv(&mut self) -> io::Result<(u8, u8)> {
+    let mut message = [0; 2];
+    self.stream.read_exact(&mut message)?;
+    Ok((message[0], message[1]))
+  }
+
+  // Sends a byte clocked by this side, and waits for the peer to answer.
+  fn transfer(&mut self, data: u8) -> io::Result<u8> {
+    self.send(TRANSFER, data)?;
+    loop {
+      match self.recv()? {
+        (REPLY, data) => return Ok(data),
+        (SYNC, _) => self.peer_syncs += 1,
+        // both sides use the internal clock, neither one listens
+        (TRANSFER, _) => self.send(REPLY, 0xff)?,
+        _ => return Err(invalid_message()),
+      }
+    }
+  }
+}
+
+fn invalid_message() -> io::Error {
+  io::Error::new(io::ErrorKind::InvalidData, "invalid link cable message")
+}
+
+// A link cable to another emulator process, over TCP. Both sides stop every
+// SYNC_CYCLES until the other one gets there too, so they stay close enough
+// in time for the external clock side to answer the transfers it receives.
+pub struct TcpLink {
+  connection: Rc<RefCell<Connection>>,
+  next_sync: usize,
+}
+
+impl TcpLink {
+  // Waits for the other side to join on `addr`.
+  pub fn host(addr: impl ToSocketAddrs) -> io::Result<Self> {
+    Self::accept(&TcpListener::bind(addr)?)
+  }
+
+  pub fn accept(listener: &TcpListener) -> io::Result<Self> {
+    let (stream, _) = listener.accept()?;
+    Self::new(stream)
+  }
+
+  pub fn join(addr: impl ToSocketAddrs) -> io::Result<Self> {
+    Self::new(TcpStream::connect(addr)?)
+  }
+
+  fn new(stream: TcpStream) -> io::Result<Self> {
+    stream.set_nodelay(true)?;
+    let connection = Connection { stream, peer_syncs: 0 };
+    Ok(TcpLink { connection: Rc::new(RefCell::new(connection)), next_sync: 0 })
+  }
+
+  // The serial device to plug in the emulator driven by this link.
+  pub fn device(&self) -> Box<dyn SerialDevice> {
+    Box::new(TcpDevice { connection: Rc::clone(&self.connection) })
+  }
+
+  // Like Emulator::step_frame, stopping at every sync point.
+  pub fn step_frame(&mut self, emu: &mut Emulator) -> io::Result<()> {
+    let target = emu.memory.borrow().cycles + CYCLES_PER_FRAME;
+    loop {
+      let cycles = emu.memory.borrow().cycles;
+      if cycles >= target { return Ok(()); }
+
+      if cycles >= self.next_sync {
+        self.sync(emu)?;
+        self.next_sync = cycles + SYNC_CYCLES;
+      }
+      emu.step().map_err(io::Error::other)?;
+    }
+  }
+
+  // Answers the transfers clocked by the other side until it reaches the same point.
+  fn sync(&mut self, emu: &mut Emulator) -> io::Result<()> {
+    let mut connection = self.connection.borrow_mut();
+    connection.send(SYNC, 0)?;
+
+    while connection.peer_syncs == 0 {
+      match connection.recv()? {
+        (SYNC, _) => connection.peer_syncs += 1,
+        (TRANSFER, data) => {
+          let reply = emu.memory.borrow_mut().serial_receive(data);
+          connection.send(REPLY, reply)?;
+        }
+        _ => return Err(invalid_message()),
+      }
+    }
+    connection.peer_syncs -= 1;
+    Ok(())
+  }
+}
+
+struct TcpDevice {
+  connection: Rc<RefCell<Connection>>,
+}
+
+impl SerialDevice for TcpDevice {
+  // a closed connection is an unplugged cable
+  fn exchange(&mut self, data: u8) -> u8 {
+    self.connection.borrow_mut().transfer(data).unwrap_or(0xff)
+  }
+}
+
+// Two emulators in the same process, connected by a link cable. They are
+// stepped in lockstep, so they are never more than an instruction apart when
+// a transfer completes. No sockets, for deterministic tests.
+pub struct LinkedPair {
+  pub first: Emulator,
+  pub second: Emulator,
+}
+
+impl LinkedPair {
+  pub fn new(mut first: Emulator, mut second: Emulator) -> Self {
+    first.link(&mut second);
+    LinkedPair { first, second }
+  }
+
+  // Steps the emulator that is behind in time.
+  pub fn step(&mut self) -> Result<(), &'static str> {
+    let first = self.first.memory.borrow().cycles;
+    let second = self.second.memory.borrow().cycles;
+    if first <= second { self.first.step() } else { self.second.step() }
+  }
+
+  // Stops at the first error, the emulator that failed would always be the one behind.
+  pub fn step_frame(&mut self) -> Result<(), &'static str> {
+    let target = self.first.memory.borrow().cycles + CYCLES_PER_FRAME;
+    while self.first.memory.borrow().cycles < target || self.second.memory.borrow().cycles < target {
+      self.step()?;
+    }
+    Ok(())
+  }
+}
@@ -1,68 +1,244 @@
+use std::collections::HashMap;
 use std::env;
 use std::fs;
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, Instant};
 use sdl2;
+use sdl2::audio::{AudioQueue, AudioSpecDesired};
+use sdl2::GameControllerSubsystem;
+use sdl2::controller::{self, GameController};
+use sdl2::event::Event;
+use sdl2::keyboard::Keycode;
 use sdl2::pixels::Color;
+use sdl2::rect::Point;
 
 use tomboy_emu::Emulator;
-use tomboy_emu::definitions::CYCLES_PER_FRAME;
+use tomboy_emu::bus::apu::{SAMPLE_RATE, resampler::Resampler};
+use tomboy_emu::bus::joypad::Button;
+use tomboy_emu::bus::serial::{SerialDevice, NullDevice};
+use tomboy_emu::link::TcpLink;
+use tomboy_emu::printer::Printer;
+use tomboy_emu::definitions::{CLOCK_SPEED, CYCLES_PER_FRAME};
 use tomboy_emu::definitions::LCD_HEIGHT;
 use tomboy_emu::definitions::LCD_WIDTH;
 
 
-const PALETTE: [Color; 4] = [Color::WHITE, Color::GRAY, Color::GREY, Color::BLACK];
+const PALETTE: [Color; 4] = [Color::WHITE, Color::RGB(170, 170, 170), Color::RGB(85, 85, 85), Color::BLACK];
+const SAVE_INTERVAL_FRAMES: usize = 60 * 10;
 
-fn tile_to_2bpp(tile: &[u8]) -> Vec<Vec<u8>> {
-  let lsbit = tile.iter().step_by(2);
-  let msbit = tile.iter().skip(1).step_by(2);
+// frames recorded by --record-wav when --frames is missing, about 10 seconds
+const DEFAULT_RECORD_FRAMES: usize = 600;
 
-  let tile = msbit
-    .zip(lsbit)
-    .map(|(high, low)| {
-      let mut row = vec![];
-      for i in 0..8 {
-        let hb = (high >> (7-i)) & 1; 
-        let lb = (low >> (7-i)) & 1;
-        row.push(hb << 1 | lb);
+const AUDIO_SAMPLE_RATE: i32 = 48000;
+const AUDIO_BUFFER_SAMPLES: u16 = 1024;
+// emulation waits while more than this many stereo samples are queued
+const AUDIO_QUEUED_SAMPLES: u32 = AUDIO_BUFFER_SAMPLES as u32 * 3;
+
+// Draws the framebuffer with a batch of points for each of the 4 shades.
+fn draw_framebuffer(emu: &Emulator, ctx: &mut SDL2Context) {
+  let mut points: [Vec<Point>; 4] = Default::default();
+
+  emu.framebuffer().iter().enumerate().for_each(|(i, &shade)| {
+    let (x, y) = (i % LCD_WIDTH, i / LCD_WIDTH);
+    points[shade as usize & 0b11].push(Point::new(x as i32, y as i32));
+  });
+
+  for (color, points) in PALETTE.iter().zip(points.iter()) {
+    ctx.canvas.set_draw_color(*color);
+    ctx.canvas.draw_points(&points[..]).unwrap();
+  }
+}
+
+const BUTTONS: [(&str, Button); 8] = [
+  ("right", Button::Right), ("left", Button::Left), ("up", Button::Up), ("down", Button::Down),
+  ("a", Button::A), ("b", Button::B), ("select", Button::Select), ("start", Button::Start),
+];
+
+struct Bindings {
+  keys: HashMap<Keycode, Button>,
+  pad: HashMap<controller::Button, Button>,
+}
+
+impl Bindings {
+  fn new() -> Self {
+    let keys = HashMap::from([
+      (Keycode::Right, Button::Right), (Keycode::Left, Button::Left),
+      (Keycode::Up, Button::Up), (Keycode::Down, Button::Down),
+      (Keycode::X, Button::A), (Keycode::Z, Button::B),
+      (Keycode::Backspace, Button::Select), (Keycode::Return, Button::Start),
+    ]);
+    let pad = HashMap::from([
+      (controller::Button::DPadRight, Button::Right), (controller::Button::DPadLeft, Button::Left),
+      (controller::Button::DPadUp, Button::Up), (controller::Button::DPadDown, Button::Down),
+      (controller::Button::A, Button::A), (controller::Button::B, Button::B),
+      (controller::Button::Back, Button::Select), (controller::Button::Start, Button::Start),
+    ]);
+
+    Bindings { keys, pad }
+  }
+
+  // Every line of the file binds a button to an SDL key or controller button name,
+  // replacing the default bindings of that button:
+  //   a = X
+  //   start = pad:start
+  fn load(path: &str) -> Result<Self, String> {
+    let file = fs::read_to_string(path).map_err(|err| err.to_string())?;
+    let mut bindings = Bindings::new();
+    let mut rebound = Vec::new();
+
+    for line in file.lines().map(str::trim).filter(|line| !line.is_empty() && !line.starts_with('#')) {
+      let (name, input) = line.split_once('=').ok_or(format!("Invalid binding: {line}"))?;
+      let (name, input) = (name.trim(), input.trim());
+      let &(_, button) = BUTTONS.iter()
+        .find(|(button, _)| button.eq_ignore_ascii_case(name))
+        .ok_or(format!("Unknown button: {name}"))?;
+
+      if !rebound.contains(&button) {
+        rebound.push(button);
+        bindings.keys.retain(|_, bound| *bound != button);
+        bindings.pad.retain(|_, bound| *bound != button);
       }
-      row
-    })
-    .collect::<Vec<_>>();
 
-  tile
+      match input.strip_prefix("pad:") {
+        Some(pad_button) => {
+          let pad_button = controller::Button::from_string(pad_button).ok_or(format!("Unknown controller button: {pad_button}"))?;
+          bindings.pad.insert(pad_button, button);
+        }
+        None => {
+          let key = Keycode::from_name(input).ok_or(format!("Unknown key: {input}"))?;
+          bindings.keys.insert(key, button);
+        }
+      }
+    }
+
+    Ok(bindings)
+  }
 }
 
-fn dump_vram_tiles(emu: &Emulator, ctx: &mut SDL2Context) {
-  let mut curr_x = 0;
-  let mut curr_y = 0;
-  
-  let _ = &emu.memory.borrow()
-    .vram[..]
-    .chunks(16)
-    .map(tile_to_2bpp)
-    .enumerate()
-    .for_each(|(_, tile)| {
-      if curr_x >= 8 * 32 { curr_x = 0; curr_y += 8; }
-      draw_tile(tile, curr_x, curr_y, ctx);
-      curr_x += 8;
-    });
+// Prints what the rom sends through the link port, like the Blargg test results.
+struct StderrSerial;
+
+impl SerialDevice for StderrSerial {
+  fn exchange(&mut self, data: u8) -> u8 {
+    eprint!("{}", data as char);
+    0xff
+  }
 }
 
-fn draw_tile(tile: Vec<Vec<u8>>, x: i32, y: i32, ctx: &mut SDL2Context) {
-  tile.iter().enumerate().for_each(|(off_y, row)| {
-    row.iter().enumerate().for_each(|(off_x, &pixel)| {
-      ctx.canvas.set_draw_color(PALETTE[pixel as usize]);
-      ctx.canvas.fill_rect(sdl2::rect::Rect::new(
-        x + off_x as i32,
-        y + off_y as i32,
-        1, 1
-      )).unwrap(); 
-    })
-  })
+fn flag_value<'a>(args: &'a [String], flag: &str) -> Option<&'a str> {
+  args.iter()
+    .position(|arg| arg == flag)
+    .and_then(|i| args.get(i + 1))
+    .map(String::as_str)
+}
+
+// Headless audio capture: --record-wav <file> [--frames <n>] [--wav-channels]
+fn record_wav(emu: &mut Emulator, args: &[String], path: &str) {
+  let frames = match flag_value(args, "--frames").map(str::parse) {
+    Some(Ok(frames)) => frames,
+    Some(Err(_)) => {
+      eprintln!("Invalid number of frames.");
+      std::process::exit(1);
+    }
+    None => DEFAULT_RECORD_FRAMES,
+  };
+  let per_channel = args.iter().any(|arg| arg == "--wav-channels");
+
+  if let Err(err) = emu.record_wav(Path::new(path), frames, per_channel) {
+    eprintln!("Error writing {path}: {err}");
+    std::process::exit(1);
+  }
+}
+
+// Link cable over TCP: --host <port> waits for another instance to --join <host:port>
+fn open_link(args: &[String]) -> Option<TcpLink> {
+  let link = if let Some(port) = flag_value(args, "--host") {
+    let addr = format!("0.0.0.0:{port}");
+    eprintln!("Waiting for the other player on {addr}...");
+    TcpLink::host(addr.as_str())
+  } else if let Some(addr) = flag_value(args, "--join") {
+    TcpLink::join(addr)
+  } else {
+    return None;
+  };
+
+  match link {
+    Ok(link) => Some(link),
+    Err(err) => {
+      eprintln!("Error opening the link cable: {err}");
+      std::process::exit(1);
+    }
+  }
+}
+
+fn load_save(emu: &mut Emulator, path: &Path) {
+  if !emu.has_battery() { return; }
+
+  if let Ok(data) = fs::read(path) {
+    if let Err(msg) = emu.load_save_data(&data) {
+      eprintln!("Error loading save file {}: {msg}", path.display());
+    }
+  }
+}
+
+fn write_save(emu: &Emulator, path: &Path) {
+  if let Some(data) = emu.save_data() {
+    if let Err(err) = fs::write(path, data) {
+      eprintln!("Error writing save file {}: {err}", path.display());
+    }
+  }
+}
+
+// Resamples the emulator audio and queues it to the sound card.
+struct Audio {
+  queue: AudioQueue<f32>,
+  resampler: Resampler,
+  samples: Vec<(f32, f32)>,
+  interleaved: Vec<f32>,
+}
+
+impl Audio {
+  fn new(sdl_context: &sdl2::Sdl) -> Result<Self, String> {
+    let spec = AudioSpecDesired {
+      freq: Some(AUDIO_SAMPLE_RATE),
+      channels: Some(2),
+      samples: Some(AUDIO_BUFFER_SAMPLES),
+    };
+    let queue: AudioQueue<f32> = sdl_context.audio()?.open_queue(None, &spec)?;
+    queue.resume();
+
+    let resampler = Resampler::new(SAMPLE_RATE, queue.spec().freq as usize);
+    Ok(Audio { queue, resampler, samples: Vec::new(), interleaved: Vec::new() })
+  }
+
+  fn queue(&mut self, emu: &mut Emulator) {
+    self.samples.clear();
+    self.resampler.process(&emu.take_audio_samples(), &mut self.samples);
+
+    self.interleaved.clear();
+    self.interleaved.extend(self.samples.iter().flat_map(|&(left, right)| [left, right]));
+    if let Err(err) = self.queue.queue_audio(&self.interleaved) {
+      eprintln!("Error queueing audio: {err}");
+    }
+  }
+
+  // Blocks until the sound card consumed enough samples, which paces the emulation.
+  fn wait(&self) {
+    let max_bytes = AUDIO_QUEUED_SAMPLES * 2 * std::mem::size_of::<f32>() as u32;
+    while self.queue.size() > max_bytes {
+      thread::sleep(Duration::from_millis(1));
+    }
+  }
 }
 
 struct SDL2Context {
   pub canvas: sdl2::render::WindowCanvas,
-  pub event_pump: sdl2::EventPump
+  pub event_pump: sdl2::EventPump,
+  pub audio: Option<Audio>,
+  pub controller_subsystem: GameControllerSubsystem,
+  // controllers are closed when dropped
+  pub controllers: Vec<GameController>,
 }
 impl SDL2Context {
   pub fn new() -> Self {
@@ -75,15 +251,24 @@ impl SDL2Context {
 
     let mut canvas = window.into_canvas()
         .accelerated()
-        .present_vsync()
         .target_texture()
         .build().unwrap();
     canvas.set_scale(5., 5.).unwrap();
     let event_pump = sdl_context.event_pump().unwrap();
 
+    let audio = Audio::new(&sdl_context)
+      .map_err(|err| eprintln!("Error opening the audio device, running without sound: {err}"))
+      .ok();
+
+    // already connected controllers are reported with ControllerDeviceAdded events
+    let controller_subsystem = sdl_context.game_controller().unwrap();
+
     SDL2Context {
       canvas,
-      event_pump
+      event_pump,
+      audio,
+      controller_subsystem,
+      controllers: Vec::new(),
     }
   }
 }
@@ -103,15 +288,52 @@ fn main() {
   let rom = fs::read(rom_path)
     .expect("Error reading the file.");
 
-  let mut emu = Emulator::new(rom);
+  let mut emu = match Emulator::new(rom) {
+    Ok(emu) => emu,
+    Err(err) => {
+      eprintln!("Error loading the rom: {err}");
+      std::process::exit(1);
+    }
+  };
+  let save_path = Path::new(rom_path).with_extension("sav");
+  load_save(&mut emu, &save_path);
 
-  // for debugging without screen
-  if args.len() > 2 {
+  if let Some(path) = flag_value(&args, "--record-wav") {
+    record_wav(&mut emu, &args, path);
+    write_save(&emu, &save_path);
+    std::process::exit(0);
+  }
+
+  // for debugging without screen, any argument besides the window options
+  let window_flags = ["--bindings", "--host", "--join", "--printer"];
+  let headless = args.len() > 2 && !args.iter().any(|arg| window_flags.contains(&arg.as_str()));
+  if headless {
+    emu.connect_serial(Box::new(StderrSerial));
     emu.run();
+    write_save(&emu, &save_path);
     std::process::exit(0);
   }
 
+  let bindings = match flag_value(&args, "--bindings") {
+    Some(path) => Bindings::load(path).unwrap_or_else(|err| {
+      eprintln!("Error loading the bindings from {path}: {err}");
+      std::process::exit(1);
+    }),
+    None => Bindings::new(),
+  };
+
+  let mut link = open_link(&args);
+  if let Some(link) = &link {
+    emu.connect_serial(link.device());
+  } else if let Some(dir) = flag_value(&args, "--printer") {
+    // --printer <dir> plugs a Game Boy Printer, writing every print to dir
+    emu.connect_serial(Box::new(Printer::new(dir)));
+  }
+
   let mut ctx = SDL2Context::new();
+  let mut frames: usize = 0;
+  let frame_duration = Duration::from_secs_f64(CYCLES_PER_FRAME as f64 / CLOCK_SPEED as f64);
+  let mut next_frame = Instant::now();
 
   loop {
     ctx.canvas.set_draw_color(Color::BLACK);
@@ -119,19 +341,71 @@ fn main() {
 
     for event in ctx.event_pump.poll_iter() {
       match event {
-        sdl2::event::Event::Quit {..} => std::process::exit(0),
+        Event::Quit {..} => {
+          write_save(&emu, &save_path);
+          std::process::exit(0);
+        }
+        // 1 to 4 mute and unmute the audio channels
+        Event::KeyDown { keycode: Some(key @ (Keycode::Num1 | Keycode::Num2 | Keycode::Num3 | Keycode::Num4)), repeat: false, .. } => {
+          let channel = key as i32 - Keycode::Num1 as i32;
+          emu.set_audio_mute_mask(emu.audio_mute_mask() ^ (1 << channel));
+        }
+        Event::KeyDown { keycode: Some(key), repeat: false, .. } => {
+          if let Some(&button) = bindings.keys.get(&key) { emu.press(button); }
+        }
+        Event::KeyUp { keycode: Some(key), .. } => {
+          if let Some(&button) = bindings.keys.get(&key) { emu.release(button); }
+        }
+        Event::ControllerDeviceAdded { which, .. } => {
+          match ctx.controller_subsystem.open(which) {
+            Ok(controller) => ctx.controllers.push(controller),
+            Err(err) => eprintln!("Error opening controller {which}: {err}"),
+          }
+        }
+        Event::ControllerButtonDown { button, .. } => {
+          if let Some(&button) = bindings.pad.get(&button) { emu.press(button); }
+        }
+        Event::ControllerButtonUp { button, .. } => {
+          if let Some(&button) = bindings.pad.get(&button) { emu.release(button); }
+        }
         _ => ()
       }
     }
 
-    for _ in 0..CYCLES_PER_FRAME  {
-      if let Err(_) = emu.step() {
-        continue;
+    match &mut link {
+      Some(cable) => if let Err(err) = cable.step_frame(&mut emu) {
+        eprintln!("Link cable disconnected: {err}");
+        emu.connect_serial(Box::new(NullDevice));
+        link = None;
+      }
+      None => if let Err(err) = emu.step_frame() {
+        eprintln!("Emulation stopped: {err}");
+        write_save(&emu, &save_path);
+        std::process::exit(0);
       }
     }
 
-    dump_vram_tiles(&emu, &mut ctx);
+    draw_framebuffer(&emu, &mut ctx);
 
     ctx.canvas.present();
+
+    // the audio queue paces the emulation, without it the frames are timed with a clock
+    match &mut ctx.audio {
+      Some(audio) => {
+        audio.queue(&mut emu);
+        audio.wait();
+      }
+      None => {
+        emu.take_audio_samples();
+        next_frame += frame_duration;
+        thread::sleep(next_frame.saturating_duration_since(Instant::now()));
+      }
+    }
+
+    frames += 1;
+    if frames == SAVE_INTERVAL_FRAMES {
+      frames = 0;
+      write_save(&emu, &save_path);
+    }
   }
 }
\ No newline at end of file
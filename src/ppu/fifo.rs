@@ -1,26 +1,106 @@
-enum FetcherState {
+use std::collections::VecDeque;
+
+use crate::{bus::lcd::{LCD, LCDControl}, definitions::*};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum FetcherState {
   ReadTile, ReadData0, ReadData1, Sleep, Push
 }
 
-struct Fetcher {
-  state: FetcherState,
+// https://hacktix.github.io/GBEDG/ppu/#the-pixel-fetcher
+pub struct Fetcher {
+  pub state: FetcherState,
   cycles: usize,
+  pub x: u8,
+  pub window: bool,
+  tile_id: u8,
+  data_low: u8,
+  data_high: u8,
 }
 
 use FetcherState::*;
 
 impl Fetcher {
   pub fn new() -> Fetcher {
-    Fetcher { state: ReadTile, cycles: 0 }
+    Fetcher {
+      state: ReadTile, cycles: 0, x: 0, window: false,
+      tile_id: 0, data_low: 0, data_high: 0,
+    }
+  }
+
+  pub fn reset(&mut self, window: bool) {
+    self.state = ReadTile;
+    self.cycles = 0;
+    self.x = 0;
+    self.window = window;
   }
 
-  pub fn step(&mut self, cycles: usize) {
+  // `line` is the line inside the background map, or the window internal line counter.
+  pub fn step(&mut self, fifo: &mut VecDeque<u8>, lcd: &LCD, vram: &[u8], line: u8) {
     match self.state {
-      ReadTile => {},
-      ReadData0 => {},
-      ReadData1 => {},
-      Sleep => {},
+      ReadTile => if self.wait() {
+        self.tile_id = self.read_tile_id(lcd, vram, line);
+        self.state = ReadData0;
+      }
+      ReadData0 => if self.wait() {
+        self.data_low = self.read_tile_data(lcd, vram, line, 0);
+        self.state = ReadData1;
+      }
+      ReadData1 => if self.wait() {
+        self.data_high = self.read_tile_data(lcd, vram, line, 1);
+        self.state = Sleep;
+      }
+      // the fetcher only pushes a whole tile row when the fifo is empty
+      Sleep => if fifo.is_empty() { self.state = Push; }
       Push => {}
     }
+
+    if self.state == Push {
+      let enabled = lcd.ctrl.contains(LCDControl::BG_N_WINDOW_ENABLE);
+      for bit in (0..8).rev() {
+        let color = ((self.data_high >> bit) & 1) << 1 | ((self.data_low >> bit) & 1);
+        fifo.push_back(if enabled { color } else { 0 });
+      }
+
+      self.x = self.x.wrapping_add(1);
+      self.state = ReadTile;
+    }
   }
-}
\ No newline at end of file
+
+  // every step but the push takes 2 dots
+  fn wait(&mut self) -> bool {
+    self.cycles += 1;
+    if self.cycles < 2 { return false; }
+
+    self.cycles = 0;
+    true
+  }
+
+  fn read_tile_id(&self, lcd: &LCD, vram: &[u8], line: u8) -> u8 {
+    let ctrl = lcd.ctrl;
+
+    let (map, column) = if self.window {
+      let map = if ctrl.contains(LCDControl::WINDOW_SELECT) { VRAM_TILE_MAP_1 } else { VRAM_TILE_MAP_0 };
+      (map, self.x & 0x1f)
+    } else {
+      let map = if ctrl.contains(LCDControl::BG_TILE_SELECT) { VRAM_TILE_MAP_1 } else { VRAM_TILE_MAP_0 };
+      let scx = lcd.scroll.0;
+      (map, ((scx / 8).wrapping_add(self.x)) & 0x1f)
+    };
+
+    let addr = map + (line as usize / 8) * 32 + column as usize;
+    vram[addr - VRAM_START as usize]
+  }
+
+  fn read_tile_data(&self, lcd: &LCD, vram: &[u8], line: u8, plane: usize) -> u8 {
+    // 0x8000 addressing uses unsigned tile ids, 0x8800 addressing signed ones based at 0x9000
+    let tile = if lcd.ctrl.contains(LCDControl::TILE_SELECT) {
+      VRAM_BLOCK_0 + self.tile_id as usize * 16
+    } else {
+      VRAM_BLOCK_2.wrapping_add_signed(self.tile_id as i8 as isize * 16)
+    };
+
+    let addr = tile + (line as usize % 8) * 2 + plane;
+    vram[addr - VRAM_START as usize]
+  }
+}
@@ -1,52 +1,119 @@
-use std::{rc::Rc, cell::RefCell};
+use std::collections::VecDeque;
 
-use crate::{bus::{BUS, lcd::{LCD, LCDStatus, LCDControl}, InterruptRegister}, definitions::{LCD_WIDTH, LCD_HEIGHT, INTERRUPT_FLAG}};
+use crate::{bus::{lcd::{LCD, LCDStatus, LCDControl}, InterruptRegister}, definitions::{LCD_WIDTH, LCD_HEIGHT}};
 
 mod fifo;
+mod object;
 
+use fifo::Fetcher;
+use object::{Object, ObjectPixel, OBJECTS_PER_LINE};
+
+// dots spent fetching an object, during which no pixel is pushed to the lcd
+const OBJECT_FETCH_DOTS: usize = 6;
+// the first tile fetched on every line is thrown away, so mode 3 lasts at least 172 dots
+const FIRST_FETCH_DOTS: usize = 6;
+
+// The PPU owns the lcd registers, VRAM and OAM, and is stepped by the bus once per dot.
 pub struct PPU {
-  pub memory: Rc<RefCell<BUS>>,
+  pub lcd: LCD,
+  pub vram: [u8; 1024 * 8],
+  pub oam: [u8; 160],
+  // requested interrupts, moved to IF by the bus
+  pub interrupts: InterruptRegister,
+
   pub framebuffer: [u8; LCD_WIDTH * LCD_HEIGHT],
   pub mode: PPUMode,
   pub scanline_cycles: usize,
   pub scanline_pixels: usize,
+
+  fetcher: Fetcher,
+  bg_fifo: VecDeque<u8>,
+  pixels_to_discard: u8,
+  first_fetch_cycles: usize,
+
+  objects: Vec<Object>,
+  next_object: usize,
+  object_fetch_cycles: usize,
+  obj_fifo: VecDeque<ObjectPixel>,
+
+  window_line: u8,
+  window_y_triggered: bool,
+  window_active: bool,
+
+  lcd_enabled: bool,
+  // the first frame after turning the lcd on is not shown
+  blank_frame: bool,
+
+  // the interrupt is requested only on a rising edge of the OR of all the enabled STAT sources
+  stat_line: bool,
 }
 
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub enum PPUMode {
   HBlank, VBlank, OAMScan, Drawing
 }
 
 use PPUMode::*;
 
+impl Default for PPU {
+  fn default() -> Self { Self::new() }
+}
+
 impl PPU {
-  pub fn new(memory: Rc<RefCell<BUS>>) -> Self {
+  pub fn new() -> Self {
     PPU {
-      memory, framebuffer: [0; LCD_WIDTH * LCD_HEIGHT],
-      scanline_cycles: 0, scanline_pixels: 0, 
+      lcd: LCD::new(),
+      vram: [0; 1024 * 8],
+      oam: [0; 160],
+      interrupts: InterruptRegister::empty(),
+      framebuffer: [0; LCD_WIDTH * LCD_HEIGHT],
+      scanline_cycles: 0, scanline_pixels: 0,
       mode: OAMScan,
+      fetcher: Fetcher::new(),
+      bg_fifo: VecDeque::with_capacity(16),
+      pixels_to_discard: 0,
+      first_fetch_cycles: 0,
+      objects: Vec::with_capacity(OBJECTS_PER_LINE),
+      next_object: 0,
+      object_fetch_cycles: 0,
+      obj_fifo: VecDeque::with_capacity(8),
+      window_line: 0,
+      window_y_triggered: false,
+      window_active: false,
+      lcd_enabled: true,
+      blank_frame: false,
+      stat_line: false,
     }
   }
 
   pub fn step(&mut self) {
+    if !self.check_lcd_enabled() { return; }
+
     self.scanline_cycles += 1;
 
     match self.mode {
       OAMScan => {
-        if self.scanline_cycles == 80 {
-          self.scanline_pixels = 0;
+        if self.scanline_cycles == 1 {
+          self.objects.clear();
+          if self.get_ly() == self.get_window().1 { self.window_y_triggered = true; }
+        }
 
-          // TODO: implement sprites rendering
+        // every OAM entry takes 2 dots to be checked
+        if self.scanline_cycles.is_multiple_of(2) {
+          self.scan_oam_entry(self.scanline_cycles / 2 - 1);
+        }
 
+        if self.scanline_cycles == 80 {
+          self.start_drawing();
           self.mode = Drawing;
         }
       },
       Drawing => {
-        self.scanline_pixels += 1;
-        if self.scanline_pixels == 160 {
+        self.draw();
+        if self.scanline_pixels == LCD_WIDTH {
+          if self.window_active { self.window_line += 1; }
+
           self.mode = HBlank;
-          if self.get_lcd_stat().contains(LCDStatus::HBLANK_INT) {
-            self.send_stat_interrupt();
-          }
         }
       },
       HBlank => {
@@ -56,60 +123,248 @@ impl PPU {
 
           if self.get_ly() == 144 {
             self.mode = VBlank;
-            self.send_vblank_interrupt();
-            if self.get_lcd_stat().contains(LCDStatus::VBLANK_INT) {
-              self.send_stat_interrupt();
-            }
+            self.window_line = 0;
+            self.window_y_triggered = false;
+            self.blank_frame = false;
 
+            self.send_vblank_interrupt();
           } else { self.mode = OAMScan; }
         }
       },
       VBlank => {
         if self.scanline_cycles == 456 {
-          self.inc_ly();
+          self.scanline_cycles = 0;
 
           if self.get_ly() == 153 {
             self.mode = OAMScan;
             self.reset_ly();
-            self.scanline_cycles = 0;
-          }
+          } else { self.inc_ly(); }
         }
       }
     };
+
+    self.update_stat();
+  }
+
+  // Returns false while the lcd is off, as the PPU is halted.
+  fn check_lcd_enabled(&mut self) -> bool {
+    let enabled = self.get_lcd_ctrl().contains(LCDControl::LCD_ENABLE);
+
+    match (self.lcd_enabled, enabled) {
+      (true, false) => self.turn_off(),
+      (false, true) => {
+        self.mode = OAMScan;
+        self.blank_frame = true;
+      }
+      _ => {}
+    }
+
+    self.lcd_enabled = enabled;
+    enabled
+  }
+
+  // https://gbdev.io/pandocs/LCDC.html#lcdc7--lcd-enable
+  fn turn_off(&mut self) {
+    self.mode = HBlank;
+    self.scanline_cycles = 0;
+    self.window_line = 0;
+    self.window_y_triggered = false;
+    self.framebuffer.fill(0);
+    self.reset_ly();
+    self.lcd.stat.remove(LCDStatus::PPU_MODE);
+  }
+
+  // https://gbdev.io/pandocs/STAT.html
+  fn update_stat(&mut self) {
+    let lcd = &mut self.lcd;
+
+    let mode = match self.mode {
+      HBlank => LCDStatus::empty(),
+      VBlank => LCDStatus::PPU_MODE1,
+      OAMScan => LCDStatus::PPU_MODE2,
+      Drawing => LCDStatus::PPU_MODE,
+    };
+    lcd.stat.remove(LCDStatus::PPU_MODE);
+    lcd.stat.insert(mode);
+    lcd.stat.set(LCDStatus::LYC_EQ_LY, lcd.ly == lcd.lyc);
+
+    let stat = lcd.stat;
+    let line = (stat.contains(LCDStatus::HBLANK_INT) && self.mode == HBlank)
+      || (stat.contains(LCDStatus::VBLANK_INT) && self.mode == VBlank)
+      || (stat.contains(LCDStatus::OAM_INT) && self.mode == OAMScan)
+      || (stat.contains(LCDStatus::LYC_INT) && stat.contains(LCDStatus::LYC_EQ_LY));
+
+    if line && !self.stat_line { self.send_stat_interrupt(); }
+    self.stat_line = line;
+  }
+
+  fn start_drawing(&mut self) {
+    self.scanline_pixels = 0;
+    self.bg_fifo.clear();
+    self.fetcher.reset(false);
+    self.window_active = false;
+    self.first_fetch_cycles = FIRST_FETCH_DOTS;
+
+    // DMG priority: the object with the smaller x wins, then the one first in OAM
+    self.objects.sort_by_key(|obj| obj.x);
+    self.next_object = 0;
+    self.object_fetch_cycles = 0;
+    self.obj_fifo.clear();
+
+    // fine scrolling is done by throwing away the first pixels of the line
+    self.pixels_to_discard = self.get_scroll().0 % 8;
+  }
+
+  fn draw(&mut self) {
+    if self.first_fetch_cycles > 0 {
+      self.first_fetch_cycles -= 1;
+      return;
+    }
+
+    self.check_window_start();
+    if self.fetch_objects() { return; }
+
+    let (scroll_y, ly) = (self.get_scroll().1, self.get_ly());
+    let line = if self.fetcher.window { self.window_line } else { ly.wrapping_add(scroll_y) };
+    self.fetcher.step(&mut self.bg_fifo, &self.lcd, &self.vram, line);
+
+    let Some(color) = self.bg_fifo.pop_front() else { return; };
+    if self.pixels_to_discard > 0 {
+      self.pixels_to_discard -= 1;
+      return;
+    }
+
+    let obj = self.obj_fifo.pop_front().unwrap_or_default();
+    let shade = self.mix_pixel(color, obj);
+    if !self.blank_frame {
+      self.framebuffer[ly as usize * LCD_WIDTH + self.scanline_pixels] = shade;
+    }
+    self.scanline_pixels += 1;
+  }
+
+  fn scan_oam_entry(&mut self, index: usize) {
+    if self.objects.len() == OBJECTS_PER_LINE { return; }
+
+    let (ly, height) = (self.get_ly(), self.get_object_height());
+    let obj = Object::new(&self.oam[index * 4 .. index * 4 + 4]);
+    if obj.is_on_line(ly, height) { self.objects.push(obj); }
+  }
+
+  // Returns true while the pixel pipeline is stalled by an object fetch.
+  fn fetch_objects(&mut self) -> bool {
+    if self.object_fetch_cycles > 0 {
+      self.object_fetch_cycles -= 1;
+      if self.object_fetch_cycles == 0 { self.load_object(); }
+      return true;
+    }
+
+    if !self.get_lcd_ctrl().contains(LCDControl::SPRITE_ENABLE) { return false; }
+
+    match self.objects.get(self.next_object) {
+      Some(obj) if obj.x as usize <= self.scanline_pixels + 8 => {
+        self.object_fetch_cycles = OBJECT_FETCH_DOTS;
+        true
+      }
+      _ => false,
+    }
+  }
+
+  fn load_object(&mut self) {
+    let obj = self.objects[self.next_object];
+    self.next_object += 1;
+
+    let (ly, height) = (self.get_ly(), self.get_object_height());
+    let row = obj.fetch_row(&self.vram, ly, height);
+
+    // objects on the left border only show their rightmost pixels
+    let skip = (self.scanline_pixels + 8).saturating_sub(obj.x as usize);
+    while self.obj_fifo.len() < 8 { self.obj_fifo.push_back(ObjectPixel::default()); }
+
+    // pixels already in the fifo belong to objects with higher priority
+    for (pixel, new) in self.obj_fifo.iter_mut().zip(row.into_iter().skip(skip)) {
+      if pixel.color == 0 { *pixel = new; }
+    }
+  }
+
+  fn mix_pixel(&self, bg_color: u8, obj: ObjectPixel) -> u8 {
+    let lcd = &self.lcd;
+
+    let obj_visible = obj.color != 0 && !(obj.bg_priority && bg_color != 0);
+    let (palette, color) = match (obj_visible, obj.palette) {
+      (true, false) => (lcd.obj_palette0, obj.color),
+      (true, true) => (lcd.obj_palette1, obj.color),
+      _ => (lcd.bg_palette, bg_color),
+    };
+
+    (palette >> (color * 2)) & 0b11
+  }
+
+  fn get_object_height(&self) -> u8 {
+    if self.get_lcd_ctrl().contains(LCDControl::SPRITE_SIZE) { 16 } else { 8 }
+  }
+
+  // The window starts when the current pixel reaches WX - 7, on a frame where LY matched WY.
+  fn check_window_start(&mut self) {
+    if self.window_active || !self.window_y_triggered
+      || !self.get_lcd_ctrl().contains(LCDControl::WINDOW_ENABLE) {
+      return;
+    }
+
+    let wx = self.get_window().0;
+    if self.scanline_pixels + 7 < wx as usize { return; }
+
+    self.window_active = true;
+    self.bg_fifo.clear();
+    self.fetcher.reset(true);
+    self.pixels_to_discard = 7u8.saturating_sub(wx);
+  }
+
+  // VRAM can't be accessed by the CPU in mode 3, OAM in modes 2 and 3
+  pub fn vram_locked(&self) -> bool {
+    self.mode == Drawing
+  }
+
+  pub fn oam_locked(&self) -> bool {
+    self.mode == OAMScan || self.mode == Drawing
   }
 
   pub fn get_ly(&self) -> u8 {
-    self.memory.borrow().lcd.ly
+    self.lcd.ly
+  }
+
+  pub fn inc_ly(&mut self) {
+    self.lcd.ly += 1;
+  }
+
+  pub fn reset_ly(&mut self) {
+    self.lcd.ly = 0;
   }
 
-  pub fn inc_ly(&self) {
-    self.memory.borrow_mut().lcd.ly += 1;
+  pub fn get_scroll(&self) -> (u8, u8) {
+    self.lcd.scroll
   }
 
-  pub fn reset_ly(&self) {
-    self.memory.borrow_mut().lcd.ly = 0;
+  pub fn get_window(&self) -> (u8, u8) {
+    self.lcd.window
   }
 
   pub fn get_lcd_stat(&self) -> LCDStatus {
-    self.memory.borrow().lcd.stat
-  } 
+    self.lcd.stat
+  }
 
   pub fn get_lcd_ctrl(&self) -> LCDControl {
-    self.memory.borrow().lcd.ctrl
+    self.lcd.ctrl
   }
 
-  fn send_vblank_interrupt(&self) {
+  fn send_vblank_interrupt(&mut self) {
     self.send_interrupt(InterruptRegister::VBLANK);
   }
 
-  fn send_stat_interrupt(&self) {
+  fn send_stat_interrupt(&mut self) {
     self.send_interrupt(InterruptRegister::LCD);
   }
 
-  fn send_interrupt(&self, int: InterruptRegister) {
-    let mut bus = self.memory.borrow_mut();
-    let mut if_reg = InterruptRegister::new(bus.mem_read(INTERRUPT_FLAG));
-    if_reg.insert(int);
-    bus.mem_write(INTERRUPT_FLAG, if_reg.bits());
+  fn send_interrupt(&mut self, int: InterruptRegister) {
+    self.interrupts.insert(int);
   }
-}
\ No newline at end of file
+}
@@ -0,0 +1,72 @@
+use bitflags::bitflags;
+
+use crate::definitions::{VRAM_BLOCK_0, VRAM_START};
+
+pub const OBJECTS_PER_LINE: usize = 10;
+
+bitflags! {
+  #[derive(Clone, Copy)]
+  pub struct ObjectAttributes: u8 {
+    const PALETTE     = 1 << 4;
+    const X_FLIP      = 1 << 5;
+    const Y_FLIP      = 1 << 6;
+    const BG_PRIORITY = 1 << 7;
+  }
+}
+
+impl ObjectAttributes {
+  pub fn new(value: u8) -> Self { Self::from_bits_truncate(value) }
+}
+
+// https://gbdev.io/pandocs/OAM.html
+#[derive(Clone, Copy)]
+pub struct Object {
+  pub y: u8,
+  pub x: u8,
+  pub tile: u8,
+  pub attributes: ObjectAttributes,
+}
+
+#[derive(Clone, Copy, Default)]
+pub struct ObjectPixel {
+  pub color: u8,
+  pub palette: bool,
+  pub bg_priority: bool,
+}
+
+impl Object {
+  pub fn new(entry: &[u8]) -> Self {
+    Object {
+      y: entry[0], x: entry[1], tile: entry[2],
+      attributes: ObjectAttributes::new(entry[3]),
+    }
+  }
+
+  // y and x are stored with an offset of 16 and 8, so objects can be partially off screen
+  pub fn is_on_line(&self, ly: u8, height: u8) -> bool {
+    let line = ly as u16 + 16;
+    line >= self.y as u16 && line < self.y as u16 + height as u16
+  }
+
+  pub fn fetch_row(&self, vram: &[u8], ly: u8, height: u8) -> [ObjectPixel; 8] {
+    // the height can change after the OAM scan, the row then wraps within the object
+    let mut row = (ly as u16 + 16 - self.y as u16) as u8 & (height - 1);
+    if self.attributes.contains(ObjectAttributes::Y_FLIP) { row = height - 1 - row; }
+
+    // in 8x16 mode the lowest bit of the tile index is ignored
+    let tile = if height == 16 { self.tile & 0xfe } else { self.tile };
+    let addr = VRAM_BLOCK_0 + tile as usize * 16 + row as usize * 2 - VRAM_START as usize;
+    let (low, high) = (vram[addr], vram[addr + 1]);
+
+    let mut pixels = [ObjectPixel::default(); 8];
+    for (i, pixel) in pixels.iter_mut().enumerate() {
+      let bit = if self.attributes.contains(ObjectAttributes::X_FLIP) { i } else { 7 - i };
+      *pixel = ObjectPixel {
+        color: ((high >> bit) & 1) << 1 | ((low >> bit) & 1),
+        palette: self.attributes.contains(ObjectAttributes::PALETTE),
+        bg_priority: self.attributes.contains(ObjectAttributes::BG_PRIORITY),
+      };
+    }
+    pixels
+  }
+}
@@ -0,0 +1,224 @@
+use std::{fs::File, io::{self, BufWriter}, iter, path::PathBuf};
+
+use bitflags::bitflags;
+
+use crate::{bus::serial::SerialDevice, definitions::LCD_WIDTH};
+
+const MAGIC: [u8; 2] = [0x88, 0x33];
+const ALIVE: u8 = 0x81;
+
+const INIT: u8 = 0x01;
+const PRINT: u8 = 0x02;
+const DATA: u8 = 0x04;
+const STATUS: u8 = 0x0f;
+
+const TILES_PER_ROW: usize = LCD_WIDTH / 8;
+// a data packet holds 2 rows of tiles, and the printer memory 9 packets
+const BUFFER_SIZE: usize = 0x280 * 9;
+// status requests answered as busy after a print, while the paper would be moving
+const PRINTING_POLLS: usize = 4;
+
+// The 4 shades of the paper, as in the window.
+const SHADES: [u8; 4] = [255, 170, 85, 0];
+
+bitflags! {
+  #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+  pub struct PrinterStatus: u8 {
+    const CHECKSUM_ERROR = 1 << 0;
+    const PRINTING       = 1 << 1;
+    const IMAGE_FULL     = 1 << 2;
+    const UNPROCESSED    = 1 << 3;
+    const PACKET_ERROR   = 1 << 4;
+    const PAPER_JAM      = 1 << 5;
+    const OTHER_ERROR    = 1 << 6;
+    const LOW_BATTERY    = 1 << 7;
+  }
+}
+
+// Where the next byte of a packet goes.
+#[derive(Clone, Copy)]
+enum Stage {
+  Magic(usize),
+  Header(usize),
+  Data,
+  Checksum(usize),
+  KeepAlive,
+  Status,
+}
+
+// https://gbdev.io/pandocs/Gameboy_Printer.html
+// Every print is written to the output directory as print_001.png, print_002.png...
+pub struct Printer {
+  dir: PathBuf,
+  prints: usize,
+
+  stage: Stage,
+  // command, compression, length
+  header: [u8; 4],
+  data: Vec<u8>,
+  checksum: u16,
+  sum: u16,
+
+  status: PrinterStatus,
+  printing_polls: usize,
+  // the 2bpp tiles received since the last print, 20 per row
+  buffer: Vec<u8>,
+}
+
+impl Printer {
+  pub fn new(dir: impl Into<PathBuf>) -> Self {
+    Printer {
+      dir: dir.into(),
+      prints: 0,
+      stage: Stage::Magic(0),
+      header: [0; 4],
+      data: Vec::new(),
+      checksum: 0,
+      sum: 0,
+      status: PrinterStatus::empty(),
+      printing_polls: 0,
+      buffer: Vec::new(),
+    }
+  }
+
+  fn length(&self) -> usize {
+    u16::from_le_bytes([self.header[2], self.header[3]]) as usize
+  }
+
+  fn handle_packet(&mut self) {
+    if self.sum != self.checksum {
+      self.status.insert(PrinterStatus::CHECKSUM_ERROR);
+      return;
+    }
+    self.status.remove(PrinterStatus::CHECKSUM_ERROR);
+
+    match self.header[0] {
+      INIT => {
+        self.buffer.clear();
+        self.status = PrinterStatus::empty();
+        self.printing_polls = 0;
+      }
+      PRINT if self.data.len() == 4 => {
+        if let Err(err) = self.print(self.data[2]) {
+          eprintln!("Error writing the printed image: {err}");
+        }
+      }
+      DATA => {
+        if self.header[1] & 1 != 0 {
+          decompress(&self.data, &mut self.buffer);
+        } else {
+          self.buffer.extend_from_slice(&self.data);
+        }
+        self.buffer.truncate(BUFFER_SIZE);
+
+        if !self.buffer.is_empty() { self.status.insert(PrinterStatus::UNPROCESSED); }
+        if self.buffer.len() == BUFFER_SIZE { self.status.insert(PrinterStatus::IMAGE_FULL); }
+      }
+      STATUS => if self.printing_polls > 0 {
+        self.printing_polls -= 1;
+        if self.printing_polls == 0 { self.status.remove(PrinterStatus::PRINTING); }
+      }
+      _ => self.status.insert(PrinterStatus::PACKET_ERROR),
+    }
+  }
+
+  // The margins and the exposure only move the paper and darken the print, they are ignored.
+  fn print(&mut self, palette: u8) -> io::Result<()> {
+    // 0 behaves as the usual 0xe4
+    let palette = if palette == 0 { 0xe4 } else { palette };
+    let rows = self.buffer.len() / (TILES_PER_ROW * 16);
+    let (width, height) = (LCD_WIDTH, rows * 8);
+
+    let mut pixels = vec![0; width * height];
+    for (y, line) in pixels.chunks_mut(width).enumerate() {
+      for (x, pixel) in line.iter_mut().enumerate() {
+        let tile = (y / 8) * TILES_PER_ROW + x / 8;
+        let addr = tile * 16 + (y % 8) * 2;
+        let bit = 7 - x % 8;
+        let color = (self.buffer[addr] >> bit) & 1 | ((self.buffer[addr + 1] >> bit) & 1) << 1;
+        *pixel = SHADES[((palette >> (color * 2)) & 0b11) as usize];
+      }
+    }
+
+    self.buffer.clear();
+    self.status.remove(PrinterStatus::UNPROCESSED | PrinterStatus::IMAGE_FULL);
+    self.status.insert(PrinterStatus::PRINTING);
+    self.printing_polls = PRINTING_POLLS;
+
+    if height == 0 { return Ok(()); }
+    self.prints += 1;
+    let path = self.dir.join(format!("print_{:03}.png", self.prints));
+
+    let mut encoder = png::Encoder::new(BufWriter::new(File::create(path)?), width as u32, height as u32);
+    encoder.set_color(png::ColorType::Grayscale);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.write_header()
+      .and_then(|mut writer| writer.write_image_data(&pixels))
+      .map_err(io::Error::other)
+  }
+}
+
+// Runs of a repeated byte have the top bit of their length set.
+fn decompress(data: &[u8], output: &mut Vec<u8>) {
+  let mut i = 0;
+  while i < data.len() {
+    let control = data[i] as usize;
+    i += 1;
+
+    if control & 0x80 != 0 {
+      if let Some(&byte) = data.get(i) {
+        output.extend(iter::repeat_n(byte, (control & 0x7f) + 2));
+      }
+      i += 1;
+    } else {
+      let end = (i + control + 1).min(data.len());
+      output.extend_from_slice(&data[i..end]);
+      i = end;
+    }
+  }
+}
+
+impl SerialDevice for Printer {
+  // The printer answers 0 to every byte of a packet, except the last two:
+  // the alive byte, then its status.
+  fn exchange(&mut self, data: u8) -> u8 {
+    let (next, reply) = match self.stage {
+      Stage::Magic(i) if data == MAGIC[i] => {
+        self.sum = 0;
+        (if i == 0 { Stage::Magic(1) } else { Stage::Header(0) }, 0)
+      }
+      Stage::Magic(_) => (Stage::Magic((data == MAGIC[0]) as usize), 0),
+      Stage::Header(i) => {
+        self.header[i] = data;
+        self.sum = self.sum.wrapping_add(data as u16);
+        self.data.clear();
+        match i {
+          3 if self.length() == 0 => (Stage::Checksum(0), 0),
+          3 => (Stage::Data, 0),
+          _ => (Stage::Header(i + 1), 0),
+        }
+      }
+      Stage::Data => {
+        self.data.push(data);
+        self.sum = self.sum.wrapping_add(data as u16);
+        (if self.data.len() == self.length() { Stage::Checksum(0) } else { Stage::Data }, 0)
+      }
+      Stage::Checksum(0) => {
+        self.checksum = data as u16;
+        (Stage::Checksum(1), 0)
+      }
+      Stage::Checksum(_) => {
+        self.checksum |= (data as u16) << 8;
+        (Stage::KeepAlive, 0)
+      }
+      Stage::KeepAlive => (Stage::Status, ALIVE),
+      Stage::Status => {
+        self.handle_packet();
+        (Stage::Magic(0), self.status.bits())
+      }
+    };
+
+    self.stage = next;
+    reply
+  }
+}
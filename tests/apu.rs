@@ -0,0 +1,160 @@
+mod common;
+
+use tomboy_emu::Emulator;
+use tomboy_emu::bus::apu::{SAMPLE_RATE, resampler::Resampler};
+use tomboy_emu::definitions::CLOCK_SPEED;
+use common::{make_emu, read, write, tick};
+
+#[test]
+fn registers_read_back() {
+  let emu = make_emu();
+  write(&emu, 0xff11, 0x80 | 0x3f);
+  write(&emu, 0xff12, 0xf3);
+  write(&emu, 0xff13, 0x42);
+  assert_eq!((read(&emu, 0xff11), read(&emu, 0xff12), read(&emu, 0xff13)), (0xbf, 0xf3, 0xff));
+  assert_eq!(read(&emu, 0xff15), 0xff);
+  assert_eq!(read(&emu, 0xff26), 0xf0);
+
+  write(&emu, 0xff30, 0x12);
+  assert_eq!(read(&emu, 0xff30), 0x12);
+
+  // powering off clears the registers and ignores writes, but keeps the wave RAM
+  write(&emu, 0xff26, 0x00);
+  assert_eq!((read(&emu, 0xff12), read(&emu, 0xff26)), (0x00, 0x70));
+  write(&emu, 0xff12, 0xf3);
+  assert_eq!(read(&emu, 0xff12), 0x00);
+  assert_eq!(read(&emu, 0xff30), 0x12);
+
+  write(&emu, 0xff26, 0x80);
+  write(&emu, 0xff12, 0xf3);
+  assert_eq!(read(&emu, 0xff12), 0xf3);
+}
+
+#[test]
+fn length_counter_disables_channel() {
+  let emu = make_emu();
+  write(&emu, 0xff17, 0xf0);
+  // 4 length clocks left, with the length enabled
+  write(&emu, 0xff16, 60);
+  write(&emu, 0xff19, 0xc0);
+  assert_eq!(read(&emu, 0xff26) & 0x0f, 0x02);
+
+  // the length is clocked at 256 Hz
+  tick(&emu, CLOCK_SPEED / 256 * 3);
+  assert_eq!(read(&emu, 0xff26) & 0x0f, 0x02);
+  tick(&emu, CLOCK_SPEED / 256 * 2);
+  assert_eq!(read(&emu, 0xff26) & 0x0f, 0x00);
+
+  // turning the DAC off disables the channel right away
+  write(&emu, 0xff19, 0x80);
+  assert_eq!(read(&emu, 0xff26) & 0x0f, 0x02);
+  write(&emu, 0xff17, 0x00);
+  assert_eq!(read(&emu, 0xff26) & 0x0f, 0x00);
+}
+
+// 50% duty at 1 KiHz, full volume, on both sides
+fn play_square(emu: &Emulator) {
+  write(emu, 0xff24, 0x77);
+  write(emu, 0xff25, 0x22);
+  write(emu, 0xff16, 0x80);
+  write(emu, 0xff17, 0xf0);
+  let frequency = 2048 - 131072 / 1024;
+  write(emu, 0xff18, frequency as u8);
+  write(emu, 0xff19, 0x80 | (frequency >> 8) as u8);
+}
+
+#[test]
+fn square_wave_samples() {
+  let mut emu = make_emu();
+  play_square(&emu);
+
+  emu.take_audio_samples();
+  tick(&emu, CLOCK_SPEED / 10);
+  let samples = emu.take_audio_samples();
+  assert_eq!(samples.len(), SAMPLE_RATE / 10);
+  assert!(samples.iter().all(|&(left, right)| left == right));
+
+  // count the rising edges of the signal: one per period
+  let edges = samples.windows(2).filter(|pair| pair[0].0 < 0. && pair[1].0 >= 0.).count();
+  assert!((100 ..= 103).contains(&edges), "{edges}");
+
+  // panned only on the right
+  write(&emu, 0xff25, 0x02);
+  tick(&emu, CLOCK_SPEED / 10);
+  let samples = emu.take_audio_samples();
+  assert!(samples[1000..].iter().all(|&(left, _)| left.abs() < 0.01));
+  assert!(samples.iter().any(|&(_, right)| right.abs() > 0.1));
+}
+
+#[test]
+fn mute_mask() {
+  let mut emu = make_emu();
+  play_square(&emu);
+
+  emu.set_audio_mute_mask(0b0010);
+  tick(&emu, CLOCK_SPEED / 10);
+  let samples = emu.take_audio_samples();
+  assert!(samples.iter().all(|&(left, right)| left == 0. && right == 0.));
+  // the channel still runs
+  assert_eq!(read(&emu, 0xff26) & 0x0f, 0x02);
+
+  emu.set_audio_mute_mask(0b1101);
+  tick(&emu, CLOCK_SPEED / 10);
+  assert!(emu.take_audio_samples().iter().any(|&(left, _)| left.abs() > 0.1));
+}
+
+fn peak(samples: &[(f32, f32)]) -> f32 {
+  samples[samples.len() / 2 ..].iter().fold(0., |peak, &(left, _)| peak.max(left.abs()))
+}
+
+#[test]
+fn resampler_rate_and_filter() {
+  let tone = |frequency: f64| -> Vec<(f32, f32)> {
+    (0..SAMPLE_RATE / 10).map(|i| {
+      let sample = (i as f64 * frequency * 2. * std::f64::consts::PI / SAMPLE_RATE as f64).sin() as f32;
+      (sample, sample)
+    }).collect()
+  };
+
+  let mut output = Vec::new();
+  Resampler::new(SAMPLE_RATE, 48000).process(&tone(1000.), &mut output);
+  assert!((4799 ..= 4801).contains(&output.len()), "{}", output.len());
+  assert!(peak(&output) > 0.9);
+
+  // frequencies above the output nyquist are filtered out instead of aliasing
+  let mut output = Vec::new();
+  Resampler::new(SAMPLE_RATE, 48000).process(&tone(100_000.), &mut output);
+  assert!(peak(&output) < 0.01, "{}", peak(&output));
+}
+
+#[test]
+fn wav_recording() {
+  let mut emu = make_emu();
+  // the rom is all NOPs, looping back at the end of the bank
+  emu.cpu.pc = 0x150;
+  play_square(&emu);
+
+  let dir = std::env::temp_dir().join(format!("tomboy-wav-{}", std::process::id()));
+  std::fs::create_dir_all(&dir).unwrap();
+  let path = dir.join("out.wav");
+  emu.record_wav(&path, 6, true).unwrap();
+
+  let wav = std::fs::read(&path).unwrap();
+  assert_eq!(&wav[0..4], b"RIFF");
+  assert_eq!(&wav[8..16], b"WAVEfmt ");
+  assert_eq!(u32::from_le_bytes(wav[24..28].try_into().unwrap()), 44100);
+  assert_eq!(u16::from_le_bytes(wav[34..36].try_into().unwrap()), 16);
+
+  // 6 frames at ~59.7 fps
+  let samples = u32::from_le_bytes(wav[40..44].try_into().unwrap()) as usize / 4;
+  assert!((4430 ..= 4440).contains(&samples), "{samples}");
+  assert_eq!(wav.len(), 44 + samples * 4);
+
+  let ch2 = std::fs::read(dir.join("out.ch2.wav")).unwrap();
+  let ch1 = std::fs::read(dir.join("out.ch1.wav")).unwrap();
+  assert_eq!(ch2.len(), wav.len());
+  assert!(ch2[44..].iter().any(|&byte| byte != 0));
+  assert!(ch1[44..].iter().all(|&byte| byte == 0));
+
+  std::fs::remove_dir_all(&dir).unwrap();
+}
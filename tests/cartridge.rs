@@ -0,0 +1,266 @@
+mod common;
+
+use tomboy_emu::{Emulator, definitions::CLOCK_SPEED};
+use tomboy_emu::cartrdige::{ROM_BANK_SIZE, NINTENDO_LOGO, CartridgeData, HeaderError, CartType, RomSize, Destination, CgbSupport};
+use tomboy_emu::cartrdige::header::{compute_header_checksum, compute_global_checksum};
+use common::{read, write, tick};
+
+// Builds a rom where the first two bytes of every bank hold the bank number.
+fn make_rom(cart_type: u8, rom_size: u8, ram_size: u8) -> Vec<u8> {
+  let banks = 2 << rom_size;
+  let mut rom = vec![0; banks * ROM_BANK_SIZE];
+  for bank in 0..banks {
+    rom[bank * ROM_BANK_SIZE] = bank as u8;
+    rom[bank * ROM_BANK_SIZE + 1] = (bank >> 8) as u8;
+  }
+
+  rom[0x104 .. 0x134].copy_from_slice(&NINTENDO_LOGO);
+  rom[0x147] = cart_type;
+  rom[0x148] = rom_size;
+  rom[0x149] = ram_size;
+  rom
+}
+
+#[test]
+fn mbc1_rom_banking() {
+  let emu = Emulator::new(make_rom(0x01, 0x06, 0x00)).unwrap();
+  assert_eq!(read(&emu, 0x4000), 1);
+
+  write(&emu, 0x2000, 0x05);
+  assert_eq!(read(&emu, 0x4000), 0x05);
+
+  // bank 0 can't be mapped in the switchable area
+  write(&emu, 0x2000, 0x00);
+  assert_eq!(read(&emu, 0x4000), 0x01);
+
+  // neither can 0x20, 0x40 and 0x60
+  write(&emu, 0x4000, 0x01);
+  assert_eq!(read(&emu, 0x4000), 0x21);
+
+  // mode 1 maps the upper bits on the fixed area too
+  assert_eq!(read(&emu, 0x0000), 0x00);
+  write(&emu, 0x6000, 0x01);
+  assert_eq!(read(&emu, 0x0000), 0x20);
+}
+
+#[test]
+fn mbc1_ram_banking() {
+  let emu = Emulator::new(make_rom(0x03, 0x01, 0x03)).unwrap();
+
+  write(&emu, 0xa000, 0x42);
+  assert_eq!(read(&emu, 0xa000), 0xff);
+
+  write(&emu, 0x0000, 0x0a);
+  write(&emu, 0x6000, 0x01);
+  write(&emu, 0x4000, 0x02);
+  write(&emu, 0xa000, 0x42);
+  assert_eq!(read(&emu, 0xa000), 0x42);
+
+  write(&emu, 0x4000, 0x01);
+  assert_eq!(read(&emu, 0xa000), 0x00);
+  write(&emu, 0x4000, 0x02);
+  assert_eq!(read(&emu, 0xa000), 0x42);
+
+  write(&emu, 0x0000, 0x00);
+  assert_eq!(read(&emu, 0xa000), 0xff);
+}
+
+#[test]
+fn mbc1_multicart() {
+  let mut rom = make_rom(0x01, 0x05, 0x00);
+  rom[0x40104 .. 0x40134].copy_from_slice(&NINTENDO_LOGO);
+  let emu = Emulator::new(rom).unwrap();
+
+  write(&emu, 0x4000, 0x01);
+  write(&emu, 0x2000, 0x12);
+  assert_eq!(read(&emu, 0x4000), 0x12);
+
+  write(&emu, 0x6000, 0x01);
+  assert_eq!(read(&emu, 0x0000), 0x10);
+}
+
+#[test]
+fn mbc2_registers_and_ram() {
+  let emu = Emulator::new(make_rom(0x06, 0x03, 0x00)).unwrap();
+
+  // bit 8 clear selects the RAM enable register
+  write(&emu, 0x2000, 0x05);
+  assert_eq!(read(&emu, 0x4000), 0x01);
+  write(&emu, 0x2100, 0x05);
+  assert_eq!(read(&emu, 0x4000), 0x05);
+  write(&emu, 0x0100, 0x00);
+  assert_eq!(read(&emu, 0x4000), 0x01);
+
+  write(&emu, 0x0000, 0x0a);
+  write(&emu, 0xa000, 0xff);
+  assert_eq!(read(&emu, 0xa000), 0xff);
+  write(&emu, 0xa001, 0x05);
+  assert_eq!(read(&emu, 0xa001), 0xf5);
+
+  // echoed every 512 bytes
+  assert_eq!(read(&emu, 0xa201), 0xf5);
+  assert_eq!(read(&emu, 0xbe01), 0xf5);
+}
+
+fn latch_rtc(emu: &Emulator, reg: u8) -> u8 {
+  write(emu, 0x6000, 0x00);
+  write(emu, 0x6000, 0x01);
+  write(emu, 0x4000, reg);
+  read(emu, 0xa000)
+}
+
+#[test]
+fn mbc3_rtc_advances_with_cycles() {
+  let emu = Emulator::new(make_rom(0x10, 0x01, 0x03)).unwrap();
+  write(&emu, 0x0000, 0x0a);
+
+  write(&emu, 0x4000, 0x08);
+  write(&emu, 0xa000, 58);
+  tick(&emu, 3 * CLOCK_SPEED);
+
+  assert_eq!(latch_rtc(&emu, 0x08), 1);
+  assert_eq!(latch_rtc(&emu, 0x09), 1);
+
+  // the latched registers don't change until the next latch
+  tick(&emu, CLOCK_SPEED);
+  write(&emu, 0x4000, 0x08);
+  assert_eq!(read(&emu, 0xa000), 1);
+  assert_eq!(latch_rtc(&emu, 0x08), 2);
+
+  // halted clock
+  write(&emu, 0x4000, 0x0c);
+  write(&emu, 0xa000, 1 << 6);
+  tick(&emu, CLOCK_SPEED);
+  assert_eq!(latch_rtc(&emu, 0x08), 2);
+}
+
+#[test]
+fn mbc3_rtc_day_carry() {
+  let emu = Emulator::new(make_rom(0x10, 0x01, 0x03)).unwrap();
+  write(&emu, 0x0000, 0x0a);
+
+  for (reg, value) in [(0x08, 59), (0x09, 59), (0x0a, 23), (0x0b, 0xff), (0x0c, 0x01)] {
+    write(&emu, 0x4000, reg);
+    write(&emu, 0xa000, value);
+  }
+  tick(&emu, CLOCK_SPEED);
+
+  assert_eq!(latch_rtc(&emu, 0x0a), 0);
+  assert_eq!(latch_rtc(&emu, 0x0b), 0);
+  assert_eq!(latch_rtc(&emu, 0x0c), 1 << 7);
+}
+
+#[test]
+fn mbc5_rom_banking() {
+  let emu = Emulator::new(make_rom(0x19, 0x08, 0x00)).unwrap();
+
+  write(&emu, 0x2000, 0x00);
+  assert_eq!(read(&emu, 0x4000), 0x00);
+
+  write(&emu, 0x2000, 0x23);
+  write(&emu, 0x3000, 0x01);
+  assert_eq!((read(&emu, 0x4000), read(&emu, 0x4001)), (0x23, 0x01));
+
+  write(&emu, 0x3000, 0x00);
+  assert_eq!((read(&emu, 0x4000), read(&emu, 0x4001)), (0x23, 0x00));
+}
+
+#[test]
+fn mbc5_rumble() {
+  let emu = Emulator::new(make_rom(0x1e, 0x01, 0x04)).unwrap();
+  write(&emu, 0x0000, 0x0a);
+  assert!(!emu.rumble());
+
+  write(&emu, 0x4000, 0x09);
+  assert!(emu.rumble());
+  write(&emu, 0xa000, 0x42);
+
+  write(&emu, 0x4000, 0x01);
+  assert!(!emu.rumble());
+  assert_eq!(read(&emu, 0xa000), 0x42);
+}
+
+#[test]
+fn battery_save_roundtrip() {
+  let emu = Emulator::new(make_rom(0x01, 0x01, 0x02)).unwrap();
+  assert_eq!(emu.save_data(), None);
+
+  let mut emu = Emulator::new(make_rom(0x03, 0x01, 0x02)).unwrap();
+  write(&emu, 0x0000, 0x0a);
+  write(&emu, 0xa010, 0x42);
+  let save = emu.save_data().unwrap();
+  assert_eq!(save.len(), 8 * 1024);
+  assert_eq!(save[0x10], 0x42);
+
+  let mut other = Emulator::new(make_rom(0x03, 0x01, 0x02)).unwrap();
+  other.load_save_data(&save).unwrap();
+  write(&other, 0x0000, 0x0a);
+  assert_eq!(read(&other, 0xa010), 0x42);
+
+  assert!(emu.load_save_data(&save[..16]).is_err());
+}
+
+#[test]
+fn mbc3_save_rtc_footer() {
+  let emu = Emulator::new(make_rom(0x10, 0x01, 0x03)).unwrap();
+  write(&emu, 0x0000, 0x0a);
+  write(&emu, 0x4000, 0x09);
+  write(&emu, 0xa000, 42);
+
+  let save = emu.save_data().unwrap();
+  assert_eq!(save.len(), 32 * 1024 + 48);
+  assert_eq!(&save[32 * 1024 + 4 .. 32 * 1024 + 8], &[42, 0, 0, 0]);
+
+  let mut other = Emulator::new(make_rom(0x10, 0x01, 0x03)).unwrap();
+  other.load_save_data(&save).unwrap();
+  write(&other, 0x0000, 0x0a);
+  assert_eq!(latch_rtc(&other, 0x09), 42);
+}
+
+#[test]
+fn header_parsing() {
+  let mut rom = make_rom(0x1b, 0x02, 0x03);
+  rom[0x134 .. 0x13f].copy_from_slice(b"POKEMON\0\0\0\0");
+  rom[0x13f .. 0x143].copy_from_slice(b"AAXE");
+  rom[0x143] = 0x80;
+  rom[0x14b] = 0x33;
+  rom[0x144 .. 0x146].copy_from_slice(b"01");
+  rom[0x14a] = 0x01;
+  rom[0x14d] = compute_header_checksum(&rom);
+  let global = compute_global_checksum(&rom).to_be_bytes();
+  rom[0x14e .. 0x150].copy_from_slice(&global);
+
+  let header = CartridgeData::new(&rom).unwrap();
+  assert_eq!(header.title, "POKEMON");
+  assert_eq!(header.manufacturer.as_deref(), Some("AAXE"));
+  assert_eq!(header.cgb, CgbSupport::Compatible);
+  assert_eq!(header.cart_type, CartType::MBC5RamBattery);
+  assert_eq!(header.cart_type.name(), "MBC5+RAM+BATTERY");
+  assert_eq!(header.rom_size, RomSize::Kib128);
+  assert_eq!(header.ram_size.bytes(), 32 * 1024);
+  assert_eq!(header.destination, Destination::Overseas);
+  assert_eq!(header.licensee.name(), "Nintendo Research & Development 1");
+  assert!(header.has_battery());
+  assert_eq!(header.validate(), Ok(()));
+
+  rom[0x1000] ^= 0xff;
+  let header = CartridgeData::new(&rom).unwrap();
+  assert!(matches!(header.validate(), Err(HeaderError::GlobalChecksum { .. })));
+
+  rom[0x104] = 0;
+  let header = CartridgeData::new(&rom).unwrap();
+  assert_eq!(header.validate(), Err(HeaderError::InvalidLogo));
+}
+
+#[test]
+fn header_errors() {
+  assert_eq!(CartridgeData::new(&[0; 0x100]).unwrap_err(), HeaderError::TooSmall(0x100));
+  assert_eq!(CartridgeData::new(&make_rom(0x42, 0x00, 0x00)).unwrap_err(), HeaderError::UnknownCartType(0x42));
+  assert_eq!(CartridgeData::new(&make_rom(0x00, 0x00, 0x07)).unwrap_err(), HeaderError::UnknownRamSize(0x07));
+  assert!(Emulator::new(vec![0; 0x10]).is_err());
+
+  // non ascii titles don't break the parsing
+  let mut rom = make_rom(0x00, 0x00, 0x00);
+  rom[0x134 .. 0x138].copy_from_slice(&[0xb0, 0xc3, 0x50, 0x80]);
+  assert_eq!(CartridgeData::new(&rom).unwrap().title, "??P?");
+}
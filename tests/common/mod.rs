@@ -0,0 +1,44 @@
+// Helpers shared by the integration tests, every test file only uses some of them.
+#![allow(dead_code)]
+
+use tomboy_emu::Emulator;
+use tomboy_emu::cartrdige::NINTENDO_LOGO;
+
+// An empty rom: the CPU runs NOPs and jumps back to 0x150 at the end of the bank.
+pub fn make_rom() -> Vec<u8> {
+  let mut rom = vec![0; 0x8000];
+  rom[0x104 .. 0x134].copy_from_slice(&NINTENDO_LOGO);
+  rom[0x7ffd .. 0x8000].copy_from_slice(&[0xc3, 0x50, 0x01]);
+  rom
+}
+
+pub fn make_emu() -> Emulator {
+  Emulator::new(make_rom()).unwrap()
+}
+
+// Runs the program from the entry point.
+pub fn make_emu_with(program: &[u8]) -> Emulator {
+  let mut rom = make_rom();
+  rom[0x100 .. 0x100 + program.len()].copy_from_slice(program);
+  Emulator::new(rom).unwrap()
+}
+
+pub fn read(emu: &Emulator, addr: u16) -> u8 {
+  emu.memory.borrow().mem_read(addr)
+}
+
+pub fn write(emu: &Emulator, addr: u16, data: u8) {
+  emu.memory.borrow_mut().mem_write(addr, data);
+}
+
+// Advances the hardware without running the CPU.
+pub fn tick(emu: &Emulator, cycles: usize) {
+  emu.memory.borrow_mut().tick(cycles);
+}
+
+// Returns true if the interrupt was requested, and clears it.
+pub fn take_interrupt(emu: &Emulator, mask: u8) -> bool {
+  let flags = read(emu, 0xff0f);
+  write(emu, 0xff0f, flags & !mask);
+  flags & mask != 0
+}
@@ -0,0 +1,105 @@
+mod common;
+
+use std::collections::BTreeSet;
+
+use tomboy_emu::cpu::optable::{OPTABLE, CB_OPTABLE};
+use common::{make_emu, write};
+
+const PROGRAM: u16 = 0xc000;
+
+// Runs a single instruction from WRAM, with every pointer into WRAM too,
+// and returns the T-cycles it took.
+fn run_instruction(code: u16, flags: u8) -> usize {
+  let mut emu = make_emu();
+  let bytes = if code > 0xff { vec![0xcb, code as u8] } else { vec![code as u8, 0x00, 0xc1] };
+  for (i, &byte) in bytes.iter().enumerate() {
+    write(&emu, PROGRAM + i as u16, byte);
+  }
+
+  emu.cpu.pc = PROGRAM;
+  emu.cpu.sp = 0xdff0;
+  emu.cpu.set_bc(0xc100);
+  emu.cpu.set_de(0xc100);
+  emu.cpu.set_hl(0xc100);
+  emu.cpu.set_af(flags as u16);
+
+  let start = emu.memory.borrow().cycles;
+  emu.step().unwrap();
+  let cycles = emu.memory.borrow().cycles;
+  cycles - start
+}
+
+// Both branches of the conditional instructions are taken, one with every
+// flag set and one with none.
+#[test]
+fn instruction_cycles_match_the_table() {
+  let skipped = ["PREFIX", "STOP", "HALT"];
+
+  let prefixed = CB_OPTABLE.iter().map(|opcode| (0xcb00 | opcode.code as u16, opcode));
+  for (code, opcode) in OPTABLE.iter().map(|opcode| (opcode.code as u16, opcode)).chain(prefixed) {
+    if skipped.contains(&opcode.name) || opcode.name.starts_with("ILLEGAL") { continue; }
+
+    let taken: BTreeSet<usize> = [0x00, 0xf0].into_iter().map(|flags| run_instruction(code, flags)).collect();
+    let expected: BTreeSet<usize> = [opcode.cycles.0, opcode.cycles.1].into_iter().filter(|&c| c != 0).collect();
+    assert_eq!(taken, expected, "{} ({code:#06x})", opcode.name);
+  }
+}
+
+// M-cycles from https://gbdev.io/pandocs/CPU_Instruction_Set.html, written
+// out independently of OPTABLE. Conditional instructions list the branch not
+// taken, 0 is skipped: STOP, HALT, the CB prefix and the illegal opcodes.
+const PAN_DOCS_CYCLES: [usize; 256] = [
+  1, 3, 2, 2, 1, 1, 2, 1, 5, 2, 2, 2, 1, 1, 2, 1,
+  0, 3, 2, 2, 1, 1, 2, 1, 3, 2, 2, 2, 1, 1, 2, 1,
+  2, 3, 2, 2, 1, 1, 2, 1, 2, 2, 2, 2, 1, 1, 2, 1,
+  2, 3, 2, 2, 3, 3, 3, 1, 2, 2, 2, 2, 1, 1, 2, 1,
+  1, 1, 1, 1, 1, 1, 2, 1, 1, 1, 1, 1, 1, 1, 2, 1,
+  1, 1, 1, 1, 1, 1, 2, 1, 1, 1, 1, 1, 1, 1, 2, 1,
+  1, 1, 1, 1, 1, 1, 2, 1, 1, 1, 1, 1, 1, 1, 2, 1,
+  2, 2, 2, 2, 2, 2, 0, 2, 1, 1, 1, 1, 1, 1, 2, 1,
+  1, 1, 1, 1, 1, 1, 2, 1, 1, 1, 1, 1, 1, 1, 2, 1,
+  1, 1, 1, 1, 1, 1, 2, 1, 1, 1, 1, 1, 1, 1, 2, 1,
+  1, 1, 1, 1, 1, 1, 2, 1, 1, 1, 1, 1, 1, 1, 2, 1,
+  1, 1, 1, 1, 1, 1, 2, 1, 1, 1, 1, 1, 1, 1, 2, 1,
+  2, 3, 3, 4, 3, 4, 2, 4, 2, 4, 3, 0, 3, 6, 2, 4,
+  2, 3, 3, 0, 3, 4, 2, 4, 2, 4, 3, 0, 3, 0, 2, 4,
+  3, 3, 2, 0, 0, 4, 2, 4, 4, 1, 4, 0, 0, 0, 2, 4,
+  3, 3, 2, 1, 0, 4, 2, 4, 3, 2, 4, 1, 0, 0, 2, 4,
+];
+
+// The extra M-cycles of a taken branch.
+fn branch_cycles(code: u8) -> usize {
+  match code {
+    0x20 | 0x28 | 0x30 | 0x38 => 1,
+    0xc2 | 0xca | 0xd2 | 0xda => 1,
+    0xc0 | 0xc8 | 0xd0 | 0xd8 => 3,
+    0xc4 | 0xcc | 0xd4 | 0xdc => 3,
+    _ => 0,
+  }
+}
+
+// Every CB instruction takes 2 M-cycles, 4 on (HL) and 3 for BIT on (HL).
+fn cb_cycles(code: u8) -> usize {
+  match code {
+    0x40 ..= 0x7f if code & 0x07 == 0x06 => 3,
+    _ if code & 0x07 == 0x06 => 4,
+    _ => 2,
+  }
+}
+
+#[test]
+fn instruction_cycles_match_pan_docs() {
+  for code in 0..=0xffu8 {
+    let cycles = PAN_DOCS_CYCLES[code as usize];
+    if cycles == 0 { continue; }
+
+    let expected: BTreeSet<usize> = [cycles, cycles + branch_cycles(code)].into_iter().map(|m| m * 4).collect();
+    let taken: BTreeSet<usize> = [0x00, 0xf0].into_iter().map(|flags| run_instruction(code as u16, flags)).collect();
+    assert_eq!(taken, expected, "{code:#04x}");
+  }
+
+  for code in 0..=0xffu8 {
+    let cycles = run_instruction(0xcb00 | code as u16, 0x00);
+    assert_eq!(cycles, cb_cycles(code) * 4, "0xcb{code:02x}");
+  }
+}
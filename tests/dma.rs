@@ -0,0 +1,70 @@
+mod common;
+
+use tomboy_emu::Emulator;
+use common::{make_emu, read, write, tick};
+
+fn oam(emu: &Emulator, offset: usize) -> u8 {
+  emu.memory.borrow().ppu.oam[offset]
+}
+
+fn fill_wram(emu: &Emulator, addr: u16, value: impl Fn(u16) -> u8) {
+  for i in 0..0xa0 { write(emu, addr + i, value(i)); }
+}
+
+#[test]
+fn one_byte_per_m_cycle() {
+  let emu = make_emu();
+  // keep the PPU out of the way
+  write(&emu, 0xff40, 0);
+  fill_wram(&emu, 0xc000, |i| i as u8 + 1);
+
+  write(&emu, 0xff46, 0xc0);
+  assert_eq!(read(&emu, 0xff46), 0xc0);
+
+  // an M-cycle to start, then the first byte at the end of the next one
+  tick(&emu, 7);
+  assert_eq!(oam(&emu, 0), 0);
+  tick(&emu, 1);
+  assert_eq!(oam(&emu, 0), 1);
+  assert_eq!(oam(&emu, 1), 0);
+
+  tick(&emu, 4 * 158);
+  assert_eq!(oam(&emu, 158), 159);
+  assert_eq!(oam(&emu, 159), 0);
+  // the CPU is locked out of OAM, and reads the DMA byte from WRAM
+  assert_eq!(read(&emu, 0xfe00), 0xff);
+  assert_eq!(read(&emu, 0xc042), 159);
+  // HRAM and the registers are still there
+  write(&emu, 0xff80, 0x12);
+  assert_eq!(read(&emu, 0xff80), 0x12);
+  assert_eq!(read(&emu, 0xff46), 0xc0);
+
+  tick(&emu, 4);
+  assert_eq!(oam(&emu, 159), 160);
+  assert_eq!(read(&emu, 0xfe00), 1);
+  assert_eq!(read(&emu, 0xc042), 0x43);
+}
+
+#[test]
+fn restart_and_echo_source() {
+  let emu = make_emu();
+  write(&emu, 0xff40, 0);
+  fill_wram(&emu, 0xc100, |_| 0x11);
+  fill_wram(&emu, 0xc000, |_| 0x22);
+
+  write(&emu, 0xff46, 0xc1);
+  tick(&emu, 4 + 4 * 10);
+  assert_eq!(oam(&emu, 9), 0x11);
+
+  // 0xe000 is the echo of WRAM, the first transfer goes on until the new one starts
+  write(&emu, 0xff46, 0xe0);
+  assert_eq!(read(&emu, 0xfe00), 0xff);
+  // writes on the bus used by the DMA are lost
+  write(&emu, 0xc000, 0x33);
+  tick(&emu, 4);
+  assert_eq!(oam(&emu, 10), 0x11);
+  tick(&emu, 4);
+  assert_eq!(oam(&emu, 0), 0x22);
+  assert_eq!(oam(&emu, 11), 0);
+  assert_eq!(read(&emu, 0xff46), 0xe0);
+}
@@ -0,0 +1,106 @@
+mod common;
+
+use tomboy_emu::Emulator;
+use tomboy_emu::bus::joypad::Button;
+use common::{make_rom, read, write, tick};
+
+const PROGRAM: u16 = 0xc000;
+
+// Loads the program in WRAM, with the timer interrupt enabled and requested.
+fn make_emu(program: &[u8]) -> Emulator {
+  let mut emu = Emulator::new(make_rom()).unwrap();
+
+  for (i, &byte) in program.iter().enumerate() {
+    write(&emu, PROGRAM + i as u16, byte);
+  }
+  write(&emu, 0xffff, 0x04);
+  write(&emu, 0xff0f, 0x04);
+  emu.cpu.pc = PROGRAM;
+  emu.cpu.sp = 0xdff0;
+  emu.cpu.ime = false;
+  emu
+}
+
+fn stack_top(emu: &Emulator) -> u16 {
+  emu.cpu.mem_read_16(emu.cpu.sp)
+}
+
+#[test]
+fn halt_bug_reads_the_next_byte_twice() {
+  // HALT, INC A, NOP
+  let mut emu = make_emu(&[0x76, 0x3c, 0x00]);
+  emu.cpu.a = 0;
+
+  for _ in 0..3 { emu.step().unwrap(); }
+  assert!(!emu.cpu.halted);
+  assert_eq!(emu.cpu.a, 2);
+  assert_eq!(emu.cpu.pc, PROGRAM + 2);
+}
+
+#[test]
+fn ei_is_delayed_by_one_instruction() {
+  // EI, INC B, NOP
+  let mut emu = make_emu(&[0xfb, 0x04, 0x00]);
+  emu.cpu.b = 0;
+
+  emu.step().unwrap();
+  emu.step().unwrap();
+  assert!(emu.cpu.ime);
+  assert_eq!(emu.cpu.b, 1);
+  assert_eq!(emu.cpu.pc, PROGRAM + 2);
+
+  emu.step().unwrap();
+  assert_eq!(stack_top(&emu), PROGRAM + 2);
+  assert_eq!(emu.cpu.pc, 0x51);
+}
+
+#[test]
+fn ei_then_halt_returns_to_the_halt() {
+  // EI, HALT, NOP
+  let mut emu = make_emu(&[0xfb, 0x76, 0x00]);
+
+  emu.step().unwrap();
+  emu.step().unwrap();
+  assert!(!emu.cpu.halted);
+  emu.step().unwrap();
+  assert_eq!(stack_top(&emu), PROGRAM + 1);
+}
+
+#[test]
+fn interrupt_during_halt_fetch_returns_after_it() {
+  // HALT, NOP
+  let mut emu = make_emu(&[0x76, 0x00]);
+  emu.cpu.ime = true;
+
+  // the interrupt was requested while the HALT was fetched, long after EI
+  emu.cpu.pc = PROGRAM + 1;
+  emu.cpu.halt();
+  assert!(!emu.cpu.halted);
+
+  emu.step().unwrap();
+  assert_eq!(stack_top(&emu), PROGRAM + 1);
+}
+
+#[test]
+fn stop_waits_for_the_joypad() {
+  // STOP, INC A
+  let mut emu = make_emu(&[0x10, 0x00, 0x3c]);
+  write(&emu, 0xffff, 0x00);
+  tick(&emu, 1024);
+  emu.cpu.a = 0;
+  // select the directions
+  write(&emu, 0xff00, 0x20);
+
+  emu.step().unwrap();
+  assert!(emu.cpu.stopped);
+  assert_eq!(read(&emu, 0xff04), 0);
+
+  for _ in 0..100 { emu.step().unwrap(); }
+  assert_eq!(emu.cpu.pc, PROGRAM + 2);
+  assert_eq!(read(&emu, 0xff04), 0);
+
+  emu.press(Button::Up);
+  emu.step().unwrap();
+  assert!(!emu.cpu.stopped);
+  assert_eq!(emu.cpu.a, 1);
+}
@@ -0,0 +1,57 @@
+mod common;
+
+use tomboy_emu::Emulator;
+use tomboy_emu::bus::joypad::Button;
+use common::{make_emu, read, write, take_interrupt};
+
+fn joypad_interrupt(emu: &Emulator) -> bool {
+  take_interrupt(emu, 0x10)
+}
+
+#[test]
+fn select_lines() {
+  let mut emu = make_emu();
+  assert_eq!(read(&emu, 0xff00), 0xff);
+
+  emu.press(Button::A);
+  emu.press(Button::Down);
+  assert_eq!(read(&emu, 0xff00), 0xff);
+
+  // bit 5 low selects the action buttons
+  write(&emu, 0xff00, 0x10);
+  assert_eq!(read(&emu, 0xff00), 0xde);
+  // bit 4 low the directions
+  write(&emu, 0xff00, 0x20);
+  assert_eq!(read(&emu, 0xff00), 0xe7);
+  // both groups are or-ed together
+  write(&emu, 0xff00, 0x00);
+  assert_eq!(read(&emu, 0xff00), 0xc6);
+
+  emu.release(Button::A);
+  assert_eq!(read(&emu, 0xff00), 0xc7);
+}
+
+#[test]
+fn joypad_interrupt_on_falling_edge() {
+  let mut emu = make_emu();
+
+  // nothing happens when the group isn't selected
+  emu.press(Button::Start);
+  assert!(!joypad_interrupt(&emu));
+  emu.release(Button::Start);
+
+  write(&emu, 0xff00, 0x10);
+  assert!(!joypad_interrupt(&emu));
+  emu.press(Button::Start);
+  assert!(joypad_interrupt(&emu));
+
+  // releasing is a rising edge
+  emu.release(Button::Start);
+  assert!(!joypad_interrupt(&emu));
+
+  // selecting a group with a pressed button lowers the line too
+  emu.press(Button::Left);
+  assert!(!joypad_interrupt(&emu));
+  write(&emu, 0xff00, 0x20);
+  assert!(joypad_interrupt(&emu));
+}
@@ -0,0 +1,94 @@
+mod common;
+
+use std::net::TcpListener;
+use std::thread;
+
+use tomboy_emu::Emulator;
+use tomboy_emu::link::{LinkedPair, TcpLink};
+use common::{make_emu_with, read, write};
+
+fn make_emu() -> Emulator {
+  // jr -2, so the CPU spins at the entry point
+  make_emu_with(&[0x18, 0xfe])
+}
+
+// ld a, data; ldh (0x01), a; ld a, control; ldh (0x02), a; jr -2
+fn start_transfer(data: u8, control: u8) -> Emulator {
+  make_emu_with(&[0x3e, data, 0xe0, 0x01, 0x3e, control, 0xe0, 0x02, 0x18, 0xfe])
+}
+
+// Starts a transfer, runs a few frames linked to the other side and returns SB and SC.
+fn transfer(mut link: TcpLink, data: u8, control: u8) -> (u8, u8) {
+  let mut emu = make_emu();
+  emu.connect_serial(link.device());
+  write(&emu, 0xff01, data);
+  write(&emu, 0xff02, control);
+
+  for _ in 0..3 { link.step_frame(&mut emu).unwrap(); }
+
+  (read(&emu, 0xff01), read(&emu, 0xff02))
+}
+
+#[test]
+fn tcp_transfer() {
+  let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+  let addr = listener.local_addr().unwrap();
+
+  let slave = thread::spawn(move || transfer(TcpLink::accept(&listener).unwrap(), 0x42, 0x80));
+  let master = transfer(TcpLink::join(addr).unwrap(), 0x17, 0x81);
+
+  assert_eq!(master, (0x42, 0x7f));
+  assert_eq!(slave.join().unwrap(), (0x17, 0x7e));
+}
+
+// The end of a Blargg test, the CPU stops at the entry point.
+fn stopped_emu() -> Emulator {
+  make_emu_with(&[0xe0, 0x26, 0x18, 0xfe])
+}
+
+#[test]
+fn tcp_link_stops_with_the_cpu() {
+  let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+  let addr = listener.local_addr().unwrap();
+
+  let other = thread::spawn(move || {
+    let mut link = TcpLink::accept(&listener).unwrap();
+    let mut emu = make_emu();
+    emu.connect_serial(link.device());
+    (0..3).try_for_each(|_| link.step_frame(&mut emu))
+  });
+
+  let mut link = TcpLink::join(addr).unwrap();
+  let mut emu = stopped_emu();
+  emu.connect_serial(link.device());
+  assert!(link.step_frame(&mut emu).is_err());
+  // the emulator holds the connection too
+  drop((link, emu));
+
+  // the other side sees the cable unplugged at its next sync
+  assert!(other.join().unwrap().is_err());
+}
+
+#[test]
+fn in_process_transfer() {
+  let mut pair = LinkedPair::new(start_transfer(0x17, 0x81), start_transfer(0x42, 0x80));
+
+  // a transfer takes 4096 cycles, a few hundred instructions later it's still going
+  for _ in 0..200 { pair.step().unwrap(); }
+  assert_eq!(read(&pair.first, 0xff02), 0xff);
+  assert_eq!(read(&pair.second, 0xff02), 0xfe);
+
+  pair.step_frame().unwrap();
+  for emu in [&pair.first, &pair.second] {
+    assert_eq!(read(emu, 0xff02) & 0x80, 0);
+    assert_ne!(read(emu, 0xff0f) & 0x08, 0);
+  }
+  assert_eq!(read(&pair.first, 0xff01), 0x42);
+  assert_eq!(read(&pair.second, 0xff01), 0x17);
+}
+
+#[test]
+fn linked_pair_stops_with_the_cpu() {
+  let mut pair = LinkedPair::new(make_emu(), stopped_emu());
+  assert!(pair.step_frame().is_err());
+}
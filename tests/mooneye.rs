@@ -0,0 +1,116 @@
+// Runs the mooneye test suite roms from a build of it:
+// https://github.com/Gekkio/mooneye-test-suite
+// They aren't part of the repository, so these tests are ignored by default:
+//   MOONEYE_DIR=path/to/mooneye-test-suite/build cargo test --test mooneye -- --ignored
+// Nothing runs them automatically, the instruction timings are also checked
+// against the Pan Docs tables in cpu_timing.rs.
+use std::{env, fs, path::PathBuf};
+
+use tomboy_emu::Emulator;
+use tomboy_emu::bus::serial::CaptureDevice;
+
+// A passing rom sends the Fibonacci numbers through the serial port, a failing one 0x42s.
+const PASS: [u8; 6] = [3, 5, 8, 13, 21, 34];
+const TIMEOUT_FRAMES: usize = 60 * 30;
+
+fn suite_dir() -> PathBuf {
+  env::var_os("MOONEYE_DIR")
+    .map(PathBuf::from)
+    .expect("MOONEYE_DIR must point to a build of the mooneye test suite")
+}
+
+fn passes(path: &PathBuf) -> bool {
+  let rom = fs::read(path).unwrap_or_else(|err| panic!("{}: {err}", path.display()));
+  let mut emu = Emulator::new(rom).unwrap();
+  let capture = CaptureDevice::new();
+  emu.connect_serial(Box::new(capture.clone()));
+
+  for _ in 0..TIMEOUT_FRAMES {
+    emu.step_frame().unwrap();
+    if capture.data.borrow().len() >= PASS.len() { break; }
+  }
+  let output = capture.data.borrow();
+  *output == PASS
+}
+
+fn run_roms(roms: &[&str]) {
+  let dir = suite_dir();
+
+  let failed: Vec<_> = roms.iter()
+    .filter(|rom| !passes(&dir.join(rom)))
+    .collect();
+  assert!(failed.is_empty(), "failed: {failed:?}");
+}
+
+#[test]
+#[ignore]
+fn timer() {
+  run_roms(&[
+    "acceptance/timer/div_write.gb",
+    "acceptance/timer/rapid_toggle.gb",
+    "acceptance/timer/tim00.gb",
+    "acceptance/timer/tim00_div_trigger.gb",
+    "acceptance/timer/tim01.gb",
+    "acceptance/timer/tim01_div_trigger.gb",
+    "acceptance/timer/tim10.gb",
+    "acceptance/timer/tim10_div_trigger.gb",
+    "acceptance/timer/tim11.gb",
+    "acceptance/timer/tim11_div_trigger.gb",
+    "acceptance/timer/tima_reload.gb",
+    "acceptance/timer/tima_write_reloading.gb",
+    "acceptance/timer/tma_write_reloading.gb",
+  ]);
+}
+
+#[test]
+#[ignore]
+fn oam_dma() {
+  run_roms(&[
+    "acceptance/oam_dma/basic.gb",
+    "acceptance/oam_dma/reg_read.gb",
+    "acceptance/oam_dma/sources-GS.gb",
+    "acceptance/oam_dma_restart.gb",
+    "acceptance/oam_dma_start.gb",
+    "acceptance/oam_dma_timing.gb",
+  ]);
+}
+
+#[test]
+#[ignore]
+fn cpu_timing() {
+  run_roms(&[
+    "acceptance/instr_timing.gb",
+    "acceptance/mem_timing.gb",
+    "acceptance/call_timing.gb",
+    "acceptance/call_cc_timing.gb",
+    "acceptance/jp_timing.gb",
+    "acceptance/jp_cc_timing.gb",
+    "acceptance/ret_timing.gb",
+    "acceptance/ret_cc_timing.gb",
+    "acceptance/reti_timing.gb",
+    "acceptance/rst_timing.gb",
+    "acceptance/push_timing.gb",
+    "acceptance/pop_timing.gb",
+    "acceptance/add_sp_e_timing.gb",
+    "acceptance/ld_hl_sp_e_timing.gb",
+    "acceptance/div_timing.gb",
+    "acceptance/intr_timing.gb",
+  ]);
+}
+
+#[test]
+#[ignore]
+fn halt_and_ei() {
+  run_roms(&[
+    "acceptance/halt_ime0_ei.gb",
+    "acceptance/halt_ime0_nointr_timing.gb",
+    "acceptance/halt_ime1_timing.gb",
+    "acceptance/halt_ime1_timing2-GS.gb",
+    "acceptance/ei_sequence.gb",
+    "acceptance/ei_timing.gb",
+    "acceptance/di_timing-GS.gb",
+    "acceptance/rapid_di_ei.gb",
+    "acceptance/reti_intr_timing.gb",
+    "acceptance/if_ie_registers.gb",
+  ]);
+}
@@ -0,0 +1,337 @@
+mod common;
+
+use tomboy_emu::Emulator;
+use tomboy_emu::definitions::LCD_WIDTH;
+use common::{make_emu, make_emu_with, read, write, take_interrupt};
+
+const DOTS_PER_FRAME: usize = 456 * 154;
+
+// Fills a tile with a single color id.
+fn fill_tile(emu: &Emulator, addr: u16, color: u8) {
+  for row in 0..8 {
+    write(emu, addr + row * 2, if color & 1 != 0 { 0xff } else { 0 });
+    write(emu, addr + row * 2 + 1, if color & 2 != 0 { 0xff } else { 0 });
+  }
+}
+
+// Advances the hardware by a single dot, without running the CPU.
+fn step(emu: &Emulator) {
+  emu.memory.borrow_mut().tick(1);
+}
+
+fn run_frame(emu: &mut Emulator) {
+  for _ in 0..DOTS_PER_FRAME { step(emu); }
+}
+
+fn pixel(emu: &Emulator, x: usize, y: usize) -> u8 {
+  emu.framebuffer()[y * LCD_WIDTH + x]
+}
+
+#[test]
+fn background_scrolling() {
+  let mut emu = make_emu();
+  write(&emu, 0xff40, 0x91);
+  write(&emu, 0xff47, 0xe4);
+
+  // tile 1 in the second map column, everything else is tile 0
+  fill_tile(&emu, 0x8010, 3);
+  write(&emu, 0x9801, 1);
+
+  run_frame(&mut emu);
+  assert_eq!((pixel(&emu, 7, 0), pixel(&emu, 8, 0), pixel(&emu, 15, 7), pixel(&emu, 16, 0)), (0, 3, 3, 0));
+
+  write(&emu, 0xff43, 3);
+  run_frame(&mut emu);
+  assert_eq!((pixel(&emu, 4, 0), pixel(&emu, 5, 0), pixel(&emu, 12, 0), pixel(&emu, 13, 0)), (0, 3, 3, 0));
+
+  // the palette maps color ids to shades
+  write(&emu, 0xff47, 0x1b);
+  run_frame(&mut emu);
+  assert_eq!((pixel(&emu, 4, 0), pixel(&emu, 5, 0)), (3, 0));
+}
+
+#[test]
+fn signed_tile_addressing() {
+  let mut emu = make_emu();
+  write(&emu, 0xff40, 0x81);
+  write(&emu, 0xff47, 0xe4);
+
+  // id 0x80 is at 0x8800 and id 0 at 0x9000
+  fill_tile(&emu, 0x8800, 2);
+  fill_tile(&emu, 0x9000, 1);
+  write(&emu, 0x9800, 0x80);
+
+  run_frame(&mut emu);
+  assert_eq!((pixel(&emu, 0, 0), pixel(&emu, 8, 0)), (2, 1));
+}
+
+#[test]
+fn window_overlay() {
+  let mut emu = make_emu();
+  // window map at 0x9c00, filled with tile 1
+  write(&emu, 0xff40, 0xf1);
+  write(&emu, 0xff47, 0xe4);
+  fill_tile(&emu, 0x8010, 2);
+  for i in 0..0x400 { write(&emu, 0x9c00 + i, 1); }
+
+  write(&emu, 0xff4a, 10);
+  write(&emu, 0xff4b, 7 + 20);
+
+  run_frame(&mut emu);
+  assert_eq!(pixel(&emu, 30, 9), 0);
+  assert_eq!((pixel(&emu, 19, 10), pixel(&emu, 20, 10), pixel(&emu, 159, 143)), (0, 2, 2));
+}
+
+fn write_object(emu: &Emulator, index: u16, y: u8, x: u8, tile: u8, attributes: u8) {
+  // written directly, as the OAM is locked while the PPU scans it
+  let start = index as usize * 4;
+  emu.memory.borrow_mut().ppu.oam[start .. start + 4].copy_from_slice(&[y, x, tile, attributes]);
+}
+
+#[test]
+fn objects_rendering() {
+  let mut emu = make_emu();
+  write(&emu, 0xff40, 0x93);
+  write(&emu, 0xff47, 0xe4);
+  write(&emu, 0xff48, 0xe4);
+  write(&emu, 0xff49, 0x1b);
+
+  // tile 1 has only its leftmost column set to color 3, tile 2 is all color 1
+  for row in 0..8 {
+    write(&emu, 0x8010 + row * 2, 0x80);
+    write(&emu, 0x8011 + row * 2, 0x80);
+  }
+  fill_tile(&emu, 0x8020, 1);
+
+  write_object(&emu, 0, 16, 8, 1, 0x00);
+  write_object(&emu, 1, 16, 24, 1, 0x20);
+  write_object(&emu, 2, 16, 40, 1, 0x10);
+  // partially off screen on the left
+  write_object(&emu, 3, 24, 4, 2, 0x00);
+
+  run_frame(&mut emu);
+  assert_eq!((pixel(&emu, 0, 0), pixel(&emu, 1, 0)), (3, 0));
+  // x flip
+  assert_eq!((pixel(&emu, 16, 0), pixel(&emu, 23, 0)), (0, 3));
+  // OBP1
+  assert_eq!(pixel(&emu, 32, 0), 0);
+  assert_eq!((pixel(&emu, 3, 8), pixel(&emu, 4, 8)), (1, 0));
+}
+
+#[test]
+fn object_height_change_after_oam_scan() {
+  let mut emu = make_emu();
+  write(&emu, 0xff40, 0x97);
+  write(&emu, 0xff48, 0xe4);
+  fill_tile(&emu, 0x8020, 1);
+
+  // row 10 of a y flipped 8x16 object on line 0
+  write_object(&emu, 0, 6, 8, 2, 0x40);
+  for _ in 0..80 { step(&emu); }
+  write(&emu, 0xff40, 0x93);
+
+  run_frame(&mut emu);
+  assert_eq!(pixel(&emu, 0, 0), 1);
+}
+
+#[test]
+fn objects_priority() {
+  let mut emu = make_emu();
+  write(&emu, 0xff40, 0x93);
+  write(&emu, 0xff47, 0xe4);
+  write(&emu, 0xff48, 0xe4);
+  fill_tile(&emu, 0x8010, 1);
+  fill_tile(&emu, 0x8020, 2);
+  fill_tile(&emu, 0x8030, 3);
+
+  // the smaller x wins, even if later in OAM
+  write_object(&emu, 0, 16, 12, 1, 0x00);
+  write_object(&emu, 1, 16, 8, 2, 0x00);
+  // with the same x, the first in OAM wins
+  write_object(&emu, 2, 16, 40, 3, 0x00);
+  write_object(&emu, 3, 16, 40, 1, 0x00);
+
+  run_frame(&mut emu);
+  assert_eq!((pixel(&emu, 3, 0), pixel(&emu, 7, 0), pixel(&emu, 8, 0)), (2, 2, 1));
+  assert_eq!(pixel(&emu, 32, 0), 3);
+
+  // background colors 1-3 are drawn over objects with the priority bit
+  write(&emu, 0x9800, 2);
+  write_object(&emu, 1, 16, 8, 1, 0x80);
+  run_frame(&mut emu);
+  assert_eq!((pixel(&emu, 0, 0), pixel(&emu, 8, 0)), (2, 1));
+}
+
+#[test]
+fn objects_per_line_limit() {
+  let mut emu = make_emu();
+  write(&emu, 0xff40, 0x97);
+  write(&emu, 0xff47, 0xe4);
+  write(&emu, 0xff48, 0xe4);
+  fill_tile(&emu, 0x8020, 1);
+  fill_tile(&emu, 0x8030, 2);
+
+  for i in 0..12 { write_object(&emu, i, 16, 8 + i as u8 * 8, 3, 0x40); }
+
+  run_frame(&mut emu);
+  // 8x16 objects ignore the low bit of the tile, y flip swaps the two tiles
+  assert_eq!((pixel(&emu, 0, 0), pixel(&emu, 0, 15)), (2, 1));
+  assert_eq!((pixel(&emu, 79, 0), pixel(&emu, 80, 0)), (2, 0));
+}
+
+// Runs until the start of the next mode 3 and returns its length in dots.
+fn mode3_length(emu: &mut Emulator) -> usize {
+  while read(emu, 0xff41) & 0b11 == 3 { step(emu); }
+  while read(emu, 0xff41) & 0b11 != 3 { step(emu); }
+
+  let mut dots = 0;
+  while read(emu, 0xff41) & 0b11 == 3 { step(emu); dots += 1; }
+  dots
+}
+
+#[test]
+fn mode3_timing() {
+  let mut emu = make_emu();
+  write(&emu, 0xff40, 0x93);
+  assert_eq!(mode3_length(&mut emu), 172);
+
+  write(&emu, 0xff43, 5);
+  assert_eq!(mode3_length(&mut emu), 177);
+
+  write(&emu, 0xff43, 0);
+  write_object(&emu, 0, 16 + 1, 50, 0, 0);
+  write_object(&emu, 1, 16 + 2, 90, 0, 0);
+  mode3_length(&mut emu);
+  assert!(mode3_length(&mut emu) > 172);
+}
+
+fn take_stat_interrupt(emu: &Emulator) -> bool {
+  take_interrupt(emu, 0x02)
+}
+
+#[test]
+fn stat_interrupts() {
+  let emu = make_emu();
+  write(&emu, 0xff40, 0x91);
+
+  // only bits 3-6 can be written
+  write(&emu, 0xff41, 0xff);
+  assert_eq!(read(&emu, 0xff41) & 0xf8, 0xf8);
+  write(&emu, 0xff41, 0x00);
+  assert_eq!(read(&emu, 0xff41) & 0xf8, 0x80);
+
+  write(&emu, 0xff45, 42);
+  write(&emu, 0xff41, 0x40);
+  while read(&emu, 0xff44) != 42 {
+    assert!(!take_stat_interrupt(&emu));
+    step(&emu);
+  }
+  assert!(read(&emu, 0xff41) & 0x04 != 0);
+  assert!(take_stat_interrupt(&emu));
+
+  // the line stays high for the whole scanline, so no new interrupt until it drops
+  for _ in 0..400 { step(&emu); }
+  assert!(!take_stat_interrupt(&emu));
+
+  // STAT blocking: enabling hblank while the LYC source holds the line high does not fire
+  write(&emu, 0xff41, 0x48);
+  for _ in 0..56 { step(&emu); }
+  assert!(!take_stat_interrupt(&emu));
+  assert_eq!(read(&emu, 0xff44), 43);
+  assert!(read(&emu, 0xff41) & 0x04 == 0);
+
+  // and the next hblank fires normally
+  while read(&emu, 0xff41) & 0b11 != 0 { step(&emu); }
+  assert!(take_stat_interrupt(&emu));
+}
+
+#[test]
+fn vram_and_oam_locking() {
+  let emu = make_emu();
+  write(&emu, 0xff40, 0x91);
+  write(&emu, 0x8000, 0x42);
+  write(&emu, 0xfe00, 0x42);
+
+  // mode 2
+  step(&emu);
+  assert_eq!((read(&emu, 0x8000), read(&emu, 0xfe00)), (0x42, 0xff));
+  write(&emu, 0xfe00, 0x00);
+
+  // mode 3
+  while read(&emu, 0xff41) & 0b11 != 3 { step(&emu); }
+  assert_eq!((read(&emu, 0x8000), read(&emu, 0xfe00)), (0xff, 0xff));
+  write(&emu, 0x8000, 0x00);
+
+  // hblank
+  while read(&emu, 0xff41) & 0b11 != 0 { step(&emu); }
+  assert_eq!((read(&emu, 0x8000), read(&emu, 0xfe00)), (0x42, 0x42));
+}
+
+#[test]
+fn lcd_off() {
+  let mut emu = make_emu();
+  write(&emu, 0xff40, 0x91);
+  write(&emu, 0xff47, 0xe4);
+  fill_tile(&emu, 0x8000, 3);
+  run_frame(&mut emu);
+  assert_eq!(pixel(&emu, 0, 0), 3);
+
+  for _ in 0..1000 { step(&emu); }
+  while read(&emu, 0xff41) & 0b11 != 3 { step(&emu); }
+  write(&emu, 0xff40, 0x11);
+  step(&emu);
+  assert_eq!((read(&emu, 0xff44), read(&emu, 0xff41) & 0b11), (0, 0));
+  assert_eq!(pixel(&emu, 0, 0), 0);
+
+  // the PPU is halted and everything is accessible
+  for _ in 0..1000 { step(&emu); }
+  assert_eq!(read(&emu, 0xff44), 0);
+  write(&emu, 0x8001, 0x00);
+  assert_eq!(read(&emu, 0x8001), 0x00);
+
+  // the first frame after turning it back on is blank
+  write(&emu, 0xff40, 0x91);
+  run_frame(&mut emu);
+  assert_eq!(pixel(&emu, 0, 0), 0);
+  run_frame(&mut emu);
+  assert_eq!(pixel(&emu, 0, 0), 1);
+}
+
+#[test]
+fn lcd_is_on_after_boot() {
+  let emu = make_emu();
+  assert_eq!(read(&emu, 0xff40), 0x91);
+  assert_eq!(read(&emu, 0xff47), 0xfc);
+
+  for _ in 0..144 * 456 { step(&emu); }
+  assert_eq!(read(&emu, 0xff44), 144);
+  assert!(take_interrupt(&emu, 0x01));
+}
+
+#[test]
+fn timing_follows_cpu_cycles() {
+  let mut emu = make_emu();
+  write(&emu, 0xff40, 0x91);
+
+  // the rom is all NOPs, 4 cycles each, looping back at the end of the bank
+  emu.cpu.pc = 0x150;
+  for _ in 0..114 { emu.step().unwrap(); }
+  assert_eq!(read(&emu, 0xff44), 1);
+
+  for _ in 0..114 * 143 { emu.step().unwrap(); }
+  assert_eq!(read(&emu, 0xff44), 144);
+  assert!(read(&emu, 0xff0f) & 0x01 != 0);
+
+  write(&emu, 0xff0f, 0x00);
+  emu.step_frame().unwrap();
+  assert_eq!(read(&emu, 0xff44), 144);
+  assert!(read(&emu, 0xff0f) & 0x01 != 0);
+}
+
+#[test]
+fn step_frame_stops_with_the_cpu() {
+  // the end of a Blargg test, LDH (0x26), A; JR -2
+  let mut emu = make_emu_with(&[0xe0, 0x26, 0x18, 0xfe]);
+
+  assert!(emu.step_frame().is_err());
+}
@@ -0,0 +1,56 @@
+use std::fs::{self, File};
+
+use tomboy_emu::bus::serial::SerialDevice;
+use tomboy_emu::printer::Printer;
+
+// Sends a whole packet, returns the alive byte and the status.
+fn send_packet(printer: &mut Printer, command: u8, compressed: bool, data: &[u8]) -> (u8, u8) {
+  let mut packet = vec![command, compressed as u8];
+  packet.extend_from_slice(&(data.len() as u16).to_le_bytes());
+  packet.extend_from_slice(data);
+  let checksum = packet.iter().fold(0u16, |sum, &byte| sum.wrapping_add(byte as u16));
+
+  for byte in [0x88, 0x33].into_iter().chain(packet).chain(checksum.to_le_bytes()) {
+    assert_eq!(printer.exchange(byte), 0);
+  }
+  (printer.exchange(0), printer.exchange(0))
+}
+
+#[test]
+fn print_to_png() {
+  let dir = std::env::temp_dir().join(format!("tomboy-printer-{}", std::process::id()));
+  fs::create_dir_all(&dir).unwrap();
+  let mut printer = Printer::new(&dir);
+
+  assert_eq!(send_packet(&mut printer, 0x01, false, &[]), (0x81, 0x00));
+
+  // the first row of tiles uses color 3, the second one color 1
+  let mut tiles = vec![0xff; 20 * 16];
+  tiles.extend([0xff, 0x00].repeat(20 * 8));
+  assert_eq!(send_packet(&mut printer, 0x04, false, &tiles).1, 0x08);
+  // the same again compressed: 0x80 | n is a run of n + 2 bytes, n alone n + 1 literals
+  let compressed = [0xff, 0xff, 0xff, 0xff, 0xbc, 0xff]
+    .into_iter()
+    .chain([0x01, 0xff, 0x00].repeat(160))
+    .collect::<Vec<_>>();
+  assert_eq!(send_packet(&mut printer, 0x04, true, &compressed).1, 0x08);
+  assert_eq!(send_packet(&mut printer, 0x04, false, &[]).1, 0x08);
+
+  // a bad checksum is reported and the packet dropped
+  for byte in [0x88, 0x33, 0x02, 0x00, 0x00, 0x00, 0x12, 0x34] { printer.exchange(byte); }
+  assert_eq!((printer.exchange(0), printer.exchange(0)), (0x81, 0x09));
+
+  // 1 sheet, no margins, inverted palette
+  assert_eq!(send_packet(&mut printer, 0x02, false, &[0x01, 0x00, 0x1b, 0x40]).1, 0x02);
+  let statuses: Vec<_> = (0..4).map(|_| send_packet(&mut printer, 0x0f, false, &[]).1).collect();
+  assert_eq!(statuses, [0x02, 0x02, 0x02, 0x00]);
+
+  let decoder = png::Decoder::new(File::open(dir.join("print_001.png")).unwrap());
+  let mut reader = decoder.read_info().unwrap();
+  let mut pixels = vec![0; reader.output_buffer_size()];
+  let info = reader.next_frame(&mut pixels).unwrap();
+  assert_eq!((info.width, info.height), (160, 32));
+  assert_eq!([pixels[0], pixels[160 * 8], pixels[160 * 16], pixels[160 * 31 + 159]], [255, 85, 255, 85]);
+
+  fs::remove_dir_all(&dir).unwrap();
+}
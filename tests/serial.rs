@@ -0,0 +1,66 @@
+mod common;
+
+use tomboy_emu::Emulator;
+use tomboy_emu::bus::serial::CaptureDevice;
+use common::{make_emu, read, write, tick, take_interrupt};
+
+const CYCLES_PER_BYTE: usize = 512 * 8;
+
+fn serial_interrupt(emu: &Emulator) -> bool {
+  take_interrupt(emu, 0x08)
+}
+
+#[test]
+fn internal_clock_transfer() {
+  let mut emu = make_emu();
+  let capture = CaptureDevice::new();
+  emu.connect_serial(Box::new(capture.clone()));
+
+  for &byte in b"ok" {
+    write(&emu, 0xff01, byte);
+    write(&emu, 0xff02, 0x81);
+    assert_eq!(read(&emu, 0xff02), 0xff);
+
+    tick(&emu, CYCLES_PER_BYTE - 1);
+    assert_eq!(read(&emu, 0xff02), 0xff);
+    assert!(!serial_interrupt(&emu));
+
+    tick(&emu, 1);
+    assert_eq!(read(&emu, 0xff02), 0x7f);
+    assert!(serial_interrupt(&emu));
+    // nothing answers from a capture buffer
+    assert_eq!(read(&emu, 0xff01), 0xff);
+  }
+
+  assert_eq!(capture.output(), "ok");
+}
+
+#[test]
+fn external_clock_waits_for_the_other_side() {
+  let mut master = make_emu();
+  let mut slave = make_emu();
+
+  write(&slave, 0xff01, 0x42);
+  write(&slave, 0xff02, 0x80);
+  tick(&slave, CYCLES_PER_BYTE * 4);
+  assert_eq!(read(&slave, 0xff02), 0xfe);
+  assert!(!serial_interrupt(&slave));
+
+  master.link(&mut slave);
+  write(&master, 0xff01, 0x17);
+  write(&master, 0xff02, 0x81);
+  tick(&master, CYCLES_PER_BYTE);
+
+  assert_eq!(read(&master, 0xff01), 0x42);
+  assert_eq!(read(&slave, 0xff01), 0x17);
+  assert_eq!(read(&slave, 0xff02), 0x7e);
+  assert!(serial_interrupt(&master));
+  assert!(serial_interrupt(&slave));
+
+  // a side that didn't start a transfer doesn't answer
+  write(&master, 0xff02, 0x81);
+  tick(&master, CYCLES_PER_BYTE);
+  assert_eq!(read(&master, 0xff01), 0xff);
+  assert_eq!(read(&slave, 0xff01), 0x17);
+  assert!(!serial_interrupt(&slave));
+}
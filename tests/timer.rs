@@ -0,0 +1,78 @@
+mod common;
+
+use tomboy_emu::Emulator;
+use common::{make_emu, read, write, tick, take_interrupt};
+
+fn timer_interrupt(emu: &Emulator) -> bool {
+  take_interrupt(emu, 0x04)
+}
+
+// Enables the timer at 262144 Hz, TIMA is clocked by the falling edge of bit 3.
+fn start_timer(emu: &Emulator, tima: u8) {
+  write(emu, 0xff07, 0x05);
+  write(emu, 0xff04, 0);
+  write(emu, 0xff05, tima);
+  timer_interrupt(emu);
+}
+
+#[test]
+fn falling_edge_increments() {
+  let emu = make_emu();
+  start_timer(&emu, 0);
+  assert_eq!(read(&emu, 0xff07), 0xfd);
+
+  tick(&emu, 15);
+  assert_eq!(read(&emu, 0xff05), 0);
+  tick(&emu, 1);
+  assert_eq!(read(&emu, 0xff05), 1);
+  tick(&emu, 16 * 10);
+  assert_eq!(read(&emu, 0xff05), 11);
+
+  // resetting DIV while bit 3 is set is a falling edge
+  tick(&emu, 8);
+  write(&emu, 0xff04, 0);
+  assert_eq!(read(&emu, 0xff05), 12);
+
+  // and so is disabling the timer
+  tick(&emu, 8);
+  write(&emu, 0xff07, 0x01);
+  assert_eq!(read(&emu, 0xff05), 13);
+  tick(&emu, 1024);
+  assert_eq!(read(&emu, 0xff05), 13);
+}
+
+#[test]
+fn delayed_reload() {
+  let emu = make_emu();
+  start_timer(&emu, 0xff);
+  write(&emu, 0xff06, 0x80);
+
+  // TIMA reads 0 for a whole M-cycle after overflowing
+  tick(&emu, 16);
+  assert_eq!(read(&emu, 0xff05), 0);
+  tick(&emu, 3);
+  assert_eq!(read(&emu, 0xff05), 0);
+  assert!(!timer_interrupt(&emu));
+  tick(&emu, 1);
+  assert_eq!(read(&emu, 0xff05), 0x80);
+  assert!(timer_interrupt(&emu));
+
+  // writing TIMA in the reload cycle is lost, but TMA goes through
+  write(&emu, 0xff05, 0x10);
+  assert_eq!(read(&emu, 0xff05), 0x80);
+  write(&emu, 0xff06, 0x90);
+  assert_eq!(read(&emu, 0xff05), 0x90);
+}
+
+#[test]
+fn writing_tima_cancels_the_reload() {
+  let emu = make_emu();
+  start_timer(&emu, 0xff);
+  write(&emu, 0xff06, 0x80);
+
+  tick(&emu, 18);
+  write(&emu, 0xff05, 0x42);
+  tick(&emu, 8);
+  assert_eq!(read(&emu, 0xff05), 0x42);
+  assert!(!timer_interrupt(&emu));
+}
@@ -43,7 +43,7 @@ function parse_obj(data, prefixed) {
     let operands = value.operands.map(e => parse_operand(e)).join(', ')
     let code = parseInt(key, 16)
 
-    return `\tOpcode {code: ${key}, prefixed: ${prefixed}, name: "${value.mnemonic}", bytes: ${value.bytes}, cycles: ${cycles}, immediate: ${value.immediate}, operands: vec!(${operands})}`
+    return `\tOpcode {code: ${key}, prefixed: ${prefixed}, name: "${value.mnemonic}", bytes: ${value.bytes}, cycles: ${cycles}, immediate: ${value.immediate}, operands: &[${operands}]}`
   }).join(', \n')
 }
 
@@ -56,5 +56,7 @@ use super::addressing::RegisterOperand::*;
 use super::addressing::LiteralOperand::*;\n\n`
 
 
-fs.writeFileSync('optable.txt', 'const opcodes: Vec<Opcode> = vec![\n' + unprefixed + ',\n\n' + cbprefixed + '\n];')
+fs.writeFileSync('optable.txt',
+  'pub const OPTABLE: [Opcode; 256] = [\n' + unprefixed + ',\n];\n\n' +
+  'pub const CB_OPTABLE: [Opcode; 256] = [\n' + cbprefixed + ',\n];')
 